@@ -1,17 +1,15 @@
-use std::cmp::Ordering;
-
 use dsa_rs::{
     binary_search::{self, BinarySearch as _, Ordering as BSOrdering},
-    binary_tree,
+    binary_tree::{self, U32Tree},
     heap::Heap,
     linked_list::SinglyLinkedList,
     priority_queue::PriorityQueue,
-    trie::Trie,
+    trie::{SuffixTrie, Trie},
 };
 
 fn main() {
     /* Binary Tree */
-    let mut bin_tree = binary_tree::generate_symmetrical_iteratively(3);
+    let mut bin_tree: U32Tree = binary_tree::generate_symmetrical_iteratively(3);
     binary_tree::print_iteratively(&bin_tree);
     binary_tree::invert_in_place_iteratively(&mut bin_tree);
     println!(" ");
@@ -35,20 +33,14 @@ fn main() {
     let ast_prefix = trie.find_all_by_prefix("ast");
     println!("words that start with 'ast' = {ast_prefix:?}");
 
-    /* Heap */
-    let comparator = |a: &i32, b: &i32| -> Ordering {
-        let r = a - b;
-        if r == 0 {
-            Ordering::Equal
-        } else if r > 0 {
-            Ordering::Greater
-        } else {
-            Ordering::Less
-        }
-    };
+    let mut suffix_trie = SuffixTrie::new();
+    words_for_trie.iter().for_each(|w| suffix_trie.insert(w));
+    let scope_suffix = suffix_trie.find_all_by_suffix("scope");
+    println!("words that end with 'scope' = {scope_suffix:?}");
 
+    /* Heap */
     let values = vec![50, 80, 30, 90, 60, 40 /*, 20*/];
-    let mut heap = Heap::new(comparator, Some(values));
+    let mut heap = Heap::new(|a: &i32, b: &i32| a.cmp(b), Some(values));
     heap.insert(20);
 
     /* Priority Queue */
@@ -1,4 +1,4 @@
-use std::cmp;
+use core::cmp;
 
 pub enum Ordering {
     Ascending,
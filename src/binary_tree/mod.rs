@@ -1,44 +1,1157 @@
-use std::collections::VecDeque;
+use crate::linked_list::SinglyLinkedList;
+
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
 
 #[derive(Default, Debug)]
-pub struct Node {
-    value: u32,
-    left: Option<Box<Node>>,
-    right: Option<Box<Node>>,
+pub struct Node<T> {
+    value: T,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+// Marks an absent child in serialized output.
+const NULL_TOKEN: &str = "null";
+
+/// Tree of `u32` values, as produced by `generate_symmetrical_iteratively`.
+pub type U32Tree = Node<u32>;
+
+/// Deep copy. Uses an explicit stack instead of recursion.
+impl<T> Clone for Node<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut root = Node::new_with_value(self.value.clone());
+        let mut stack = vec![(self, &mut root)];
+
+        while let Some((src, dst)) = stack.pop() {
+            if let Some(src_left) = src.left.as_deref() {
+                let copied = Node::new_with_value(src_left.value.clone());
+                stack.push((src_left, dst.left.insert(Box::new(copied))));
+            }
+            if let Some(src_right) = src.right.as_deref() {
+                let copied = Node::new_with_value(src_right.value.clone());
+                stack.push((src_right, dst.right.insert(Box::new(copied))));
+            }
+        }
+
+        root
+    }
+}
+
+/// Structural equality: same shape and same values. Uses an explicit stack instead of recursion.
+impl<T> PartialEq for Node<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let mut stack = vec![(self, other)];
+
+        while let Some((a, b)) = stack.pop() {
+            if a.value != b.value {
+                return false;
+            }
+            for (a_child, b_child) in [(&a.left, &b.left), (&a.right, &b.right)] {
+                match (a_child.as_deref(), b_child.as_deref()) {
+                    (None, None) => {}
+                    (Some(a_child), Some(b_child)) => stack.push((a_child, b_child)),
+                    _ => return false,
+                }
+            }
+        }
+
+        true
+    }
 }
 
-impl Node {
-    pub fn new_with_value(value: u32) -> Self {
+impl<T> Eq for Node<T> where T: Eq {}
+
+/// Serialized as the level-order array from `from_level_order`, with `null` for absent children.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Node<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.level_order_with_gaps())
+    }
+}
+
+/// Fails if the array is empty or the root is `null`.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Node<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Option<T>>::deserialize(deserializer)?;
+        Self::from_level_order_iter(values)
+            .ok_or_else(|| serde::de::Error::custom("tree must have a root value"))
+    }
+}
+
+impl<T> Node<T> {
+    pub fn new_with_value(value: T) -> Self {
         Self {
             value,
-            ..Default::default()
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Builds a tree from its level-order (breadth-first) representation, where `None` marks an
+    /// absent child (LeetCode-style). Children of absent nodes are not listed.
+    /// Returns `None` if `values` is empty, the root is absent, or a value is left over after
+    /// every open child slot has been filled (only trailing `None`s may remain).
+    pub fn from_level_order(values: &[Option<T>]) -> Option<Node<T>>
+    where
+        T: Clone,
+    {
+        Self::from_level_order_iter(values.iter().cloned())
+    }
+
+    /// Builds a height-balanced binary search tree from an already sorted slice, by repeatedly
+    /// choosing the middle element as subtree root. Returns `None` if `values` is empty.
+    /// Uses an explicit work stack of (slice range, parent slot) instead of recursion.
+    pub fn from_sorted_slice(values: &[T]) -> Option<Node<T>>
+    where
+        T: Clone,
+    {
+        if values.is_empty() {
+            return None;
+        }
+
+        let mid = values.len() / 2;
+        let mut root = Node::new_with_value(values[mid].clone());
+        let mut stack = vec![
+            (0..mid, &mut root.left),
+            (mid + 1..values.len(), &mut root.right),
+        ];
+
+        while let Some((range, slot)) = stack.pop() {
+            if range.is_empty() {
+                continue;
+            }
+
+            let mid = range.start + range.len() / 2;
+            let node = slot.insert(Box::new(Node::new_with_value(values[mid].clone())));
+            stack.push((range.start..mid, &mut node.left));
+            stack.push((mid + 1..range.end, &mut node.right));
+        }
+
+        Some(root)
+    }
+
+    /// Encodes the tree as comma-separated values in level order, using `null` for absent
+    /// children (eg. `1,2,3,null,4`). Trailing `null`s are omitted.
+    pub fn serialize(&self) -> String
+    where
+        T: Display,
+    {
+        let tokens: Vec<_> = self
+            .level_order_with_gaps()
+            .into_iter()
+            .map(|value| match value {
+                Some(v) => v.to_string(),
+                None => NULL_TOKEN.to_string(),
+            })
+            .collect();
+
+        tokens.join(",")
+    }
+
+    /// Parses the encoding produced by `serialize` back into a tree.
+    /// Returns `None` if the input is malformed or the root is absent.
+    pub fn deserialize(s: &str) -> Option<Node<T>>
+    where
+        T: FromStr + Clone,
+    {
+        let values = s
+            .split(',')
+            .map(str::trim)
+            .map(|token| match token {
+                NULL_TOKEN => Some(None),
+                _ => token.parse().ok().map(Some),
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Self::from_level_order(&values)
+    }
+
+    /// Returns the array (heap-style) layout: the root is at index 0 and the children of index `i`
+    /// are at `2i + 1` and `2i + 2`, as in `Heap`. Absent nodes are `None`; trailing `None`s are
+    /// omitted. The array grows as `2^height`, so it is compact only for complete trees.
+    ///
+    /// Panics if an index does not fit in `usize`; see `try_to_array`.
+    pub fn to_array(&self) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        self.try_to_array()
+            .expect("heap index overflowed usize; use try_to_array")
+    }
+
+    /// Like `to_array`, but returns `None` if an index does not fit in `usize`,
+    /// which happens once a path is about 64 levels deep.
+    /// Indexes are checked before anything is allocated.
+    pub fn try_to_array(&self) -> Option<Vec<Option<T>>>
+    where
+        T: Clone,
+    {
+        let mut len = 0;
+        let mut stack = vec![(self, 0usize)];
+
+        while let Some((node, index)) = stack.pop() {
+            len = len.max(index.checked_add(1)?);
+            if let Some(right) = node.right.as_deref() {
+                stack.push((right, index.checked_mul(2)?.checked_add(2)?));
+            }
+            if let Some(left) = node.left.as_deref() {
+                stack.push((left, index.checked_mul(2)?.checked_add(1)?));
+            }
+        }
+
+        let mut array = vec![None; len];
+        stack.push((self, 0));
+        while let Some((node, index)) = stack.pop() {
+            array[index] = Some(node.value.clone());
+            if let Some(right) = node.right.as_deref() {
+                stack.push((right, 2 * index + 2));
+            }
+            if let Some(left) = node.left.as_deref() {
+                stack.push((left, 2 * index + 1));
+            }
+        }
+
+        Some(array)
+    }
+
+    /// Inserts `value` as a binary search tree node. Lesser values go left,
+    /// greater or equal values go right. Does not use recursion.
+    pub fn insert_bst(&mut self, value: T)
+    where
+        T: Ord,
+    {
+        let mut current = self;
+
+        loop {
+            let next = if value < current.value {
+                &mut current.left
+            } else {
+                &mut current.right
+            };
+
+            match next {
+                Some(node) => current = node,
+                None => {
+                    *next = Some(Box::new(Node::new_with_value(value)));
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns true if `value` exists, assuming the tree is a binary search tree.
+    /// Does not use recursion.
+    pub fn contains_bst(&self, value: &T) -> bool
+    where
+        T: Ord,
+    {
+        let mut current = Some(self);
+
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return true,
+            };
+        }
+
+        false
+    }
+
+    /// Removes one node holding `value` from a binary search tree and returns the (possibly new)
+    /// root. Leaves are removed, single children are spliced into their parent's place and nodes
+    /// with two children take the value of their in-order successor. Does not use recursion.
+    pub fn delete_bst(mut root: Option<Box<Node<T>>>, value: &T) -> Option<Box<Node<T>>>
+    where
+        T: Ord,
+    {
+        let mut slot = &mut root;
+
+        loop {
+            let ordering = match slot.as_deref() {
+                Some(node) => value.cmp(&node.value),
+                None => return root,
+            };
+            match ordering {
+                Ordering::Less => slot = &mut slot.as_mut().expect("checked is_some").left,
+                Ordering::Greater => slot = &mut slot.as_mut().expect("checked is_some").right,
+                Ordering::Equal => break,
+            }
+        }
+
+        let mut node = slot.take().expect("checked is_some");
+        *slot = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let mut right = Some(right);
+                node.value = Self::take_min_bst(&mut right);
+                node.left = Some(left);
+                node.right = right;
+                Some(node)
+            }
+        };
+
+        root
+    }
+
+    /// Returns an iterator over values in in-order sequence (left, root, right).
+    /// Uses an explicit stack instead of recursion.
+    pub fn iter_in_order(&self) -> impl Iterator<Item = &T> {
+        let mut iter = InOrderIter { stack: vec![] };
+        iter.push_left_branch(Some(self));
+        iter
+    }
+
+    /// Returns an iterator over mutable values in in-order sequence (left, root, right).
+    /// Uses an explicit stack instead of recursion.
+    pub fn iter_in_order_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let mut iter = InOrderIterMut { stack: vec![] };
+        iter.push_left_branch(Some(self));
+        iter
+    }
+
+    /// Returns an iterator over values in pre-order sequence (root, left, right).
+    /// Uses an explicit stack instead of recursion.
+    pub fn iter_pre_order(&self) -> impl Iterator<Item = &T> {
+        PreOrderIter { stack: vec![self] }
+    }
+
+    /// Returns an iterator over values in post-order sequence (left, right, root).
+    /// Uses an explicit stack with a visited marker instead of recursion.
+    pub fn iter_post_order(&self) -> impl Iterator<Item = &T> {
+        PostOrderIter {
+            stack: vec![(self, false)],
+        }
+    }
+
+    /// Returns values grouped by level, top to bottom. Uses breadth-first search.
+    pub fn level_order(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.levels()
+            .map(|level| level.iter().map(|n| n.value.clone()).collect())
+            .collect()
+    }
+
+    /// Returns values grouped by level, alternating left-to-right and right-to-left,
+    /// starting left-to-right at the root. Uses breadth-first search.
+    pub fn zigzag_level_order(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.levels()
+            .enumerate()
+            .map(|(depth, level)| {
+                let values = level.iter().map(|n| n.value.clone());
+                if depth % 2 == 0 {
+                    values.collect()
+                } else {
+                    values.rev().collect()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns values grouped by column, left to right. The root is in column 0, a left child is
+    /// one column left of its parent and a right child one column right. Within a column, values
+    /// are ordered top to bottom (left to right on the same level). Uses breadth-first search.
+    pub fn vertical_order(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        let mut columns: BTreeMap<isize, Vec<T>> = BTreeMap::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((self, 0));
+
+        while let Some((node, column)) = queue.pop_front() {
+            columns.entry(column).or_default().push(node.value.clone());
+            if let Some(left) = node.left.as_deref() {
+                queue.push_back((left, column - 1));
+            }
+            if let Some(right) = node.right.as_deref() {
+                queue.push_back((right, column + 1));
+            }
+        }
+
+        columns.into_values().collect()
+    }
+
+    /// Returns number of levels in the tree. A single node has a height of 1.
+    /// Uses breadth-first search, counting queue generations.
+    pub fn height(&self) -> usize {
+        self.levels().count()
+    }
+
+    /// Returns number of nodes on the shortest root-to-leaf path. A single node has a min depth of 1.
+    /// Uses breadth-first search, stopping at the first level containing a leaf.
+    pub fn min_depth(&self) -> usize {
+        self.levels()
+            .position(|level| level.iter().any(|n| n.is_leaf()))
+            .expect("the deepest level only has leaves")
+            + 1
+    }
+
+    /// Returns the largest number of nodes present on any single level. Only nodes that exist
+    /// are counted, so gaps between sparse nodes on a level do not add to its width.
+    pub fn max_width(&self) -> usize {
+        self.levels().map(|level| level.len()).max().unwrap_or(0)
+    }
+
+    /// Returns a `SinglyLinkedList` of the tree's values in pre-order.
+    pub fn flatten_to_linked_list(&self) -> SinglyLinkedList<T>
+    where
+        T: Clone,
+    {
+        // Build back to front, since `insert_front` is O(1) while `insert_back` walks the list.
+        let pre_order: Vec<_> = self.iter_pre_order().collect();
+        let mut values = pre_order.into_iter().rev();
+        let mut list = SinglyLinkedList::new(values.next().expect("tree has a root").clone());
+        for value in values {
+            list.insert_front(value.clone());
+        }
+        list
+    }
+
+    /// Returns the value of the rightmost node on each level, top to bottom.
+    /// Uses breadth-first search.
+    pub fn right_side_view(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.levels()
+            .filter_map(|level| level.last().map(|n| n.value.clone()))
+            .collect()
+    }
+
+    /// Returns the anti-clockwise boundary: the root, the left boundary top-down, every leaf
+    /// left to right, then the right boundary bottom-up. Each node appears once, so the root
+    /// and the leaves at the ends of either boundary are not repeated. Does not use recursion.
+    pub fn boundary_traversal(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut boundary = vec![self.value.clone()];
+        if self.is_leaf() {
+            return boundary;
+        }
+
+        // Left boundary, excluding its leaf: prefer left children, fall back to right.
+        let mut curr = self.left.as_deref();
+        while let Some(node) = curr.filter(|n| !n.is_leaf()) {
+            boundary.push(node.value.clone());
+            curr = node.left.as_deref().or(node.right.as_deref());
+        }
+
+        // Leaves, left to right.
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            if node.is_leaf() {
+                boundary.push(node.value.clone());
+            }
+            stack.extend(node.right.as_deref());
+            stack.extend(node.left.as_deref());
+        }
+
+        // Right boundary, excluding its leaf, collected top-down and appended bottom-up.
+        let mut right_boundary = vec![];
+        let mut curr = self.right.as_deref();
+        while let Some(node) = curr.filter(|n| !n.is_leaf()) {
+            right_boundary.push(node.value.clone());
+            curr = node.right.as_deref().or(node.left.as_deref());
+        }
+        boundary.extend(right_boundary.into_iter().rev());
+
+        boundary
+    }
+
+    /// Returns number of nodes at `depth` (1-indexed, the root is at depth 1).
+    /// Uses breadth-first search, stopping at that level.
+    pub fn count_at_depth(&self, depth: usize) -> usize {
+        match depth.checked_sub(1) {
+            Some(skip) => self.levels().nth(skip).map_or(0, |level| level.len()),
+            None => 0,
+        }
+    }
+
+    /// Returns total number of nodes in the tree, including the root.
+    pub fn count_nodes(&self) -> usize {
+        self.iter_pre_order().count()
+    }
+
+    /// Returns number of nodes without children. Does not use recursion.
+    pub fn count_leaves(&self) -> usize {
+        let mut leaves = 0;
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            if node.is_leaf() {
+                leaves += 1;
+            }
+            if let Some(left) = node.left.as_deref() {
+                stack.push(left);
+            }
+            if let Some(right) = node.right.as_deref() {
+                stack.push(right);
+            }
+        }
+
+        leaves
+    }
+
+    /// Returns true if every level is full except possibly the last, which is filled left to right.
+    /// Uses breadth-first search: once an absent child is seen, no later node may be present.
+    pub fn is_complete(&self) -> bool {
+        let mut queue = VecDeque::new();
+        queue.push_back(Some(self));
+        let mut seen_gap = false;
+
+        while let Some(node) = queue.pop_front() {
+            match node {
+                Some(_) if seen_gap => return false,
+                Some(n) => {
+                    queue.push_back(n.left.as_deref());
+                    queue.push_back(n.right.as_deref());
+                }
+                None => seen_gap = true,
+            }
+        }
+
+        true
+    }
+
+    /// Returns true if, for every node, the heights of its left and right subtrees differ by at most one.
+    /// Heights are computed once, bottom-up, using an iterative post-order walk.
+    pub fn is_balanced(&self) -> bool {
+        self.visit_subtree_heights(|left_height, right_height| {
+            left_height.abs_diff(right_height) <= 1
+        })
+    }
+
+    /// Returns number of edges on the longest path between any two nodes.
+    /// Heights are computed once, bottom-up, using an iterative post-order walk.
+    pub fn diameter(&self) -> usize {
+        let mut diameter = 0;
+        self.visit_subtree_heights(|left_height, right_height| {
+            // Longest path through this node joins its deepest left and right branches.
+            diameter = diameter.max(left_height + right_height);
+            true
+        });
+        diameter
+    }
+
+    /// Returns true if the tree satisfies the binary search tree property, ie. an in-order
+    /// traversal is non-decreasing. Duplicates are allowed, since `insert_bst` places them right.
+    pub fn is_valid_bst(&self) -> bool
+    where
+        T: Ord,
+    {
+        let mut prev: Option<&T> = None;
+
+        for value in self.iter_in_order() {
+            if prev.is_some_and(|p| p > value) {
+                return false;
+            }
+            prev = Some(value);
+        }
+
+        true
+    }
+
+    /// Returns true if the tree is a structural mirror of itself. Values are ignored.
+    /// Uses a queue of node pairs instead of recursion.
+    pub fn is_symmetric(&self) -> bool {
+        let mut queue = VecDeque::new();
+        queue.push_back((self.left.as_deref(), self.right.as_deref()));
+
+        while let Some(pair) = queue.pop_front() {
+            match pair {
+                (None, None) => {}
+                (Some(a), Some(b)) => {
+                    queue.push_back((a.left.as_deref(), b.right.as_deref()));
+                    queue.push_back((a.right.as_deref(), b.left.as_deref()));
+                }
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Returns true if `other` is the mirror image of `self`, comparing both structure and values.
+    /// Uses a queue of node pairs, comparing `a.left` with `b.right` and vice versa.
+    pub fn is_mirror_of(&self, other: &Node<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut queue = VecDeque::new();
+        queue.push_back((Some(self), Some(other)));
+
+        while let Some(pair) = queue.pop_front() {
+            match pair {
+                (None, None) => {}
+                (Some(a), Some(b)) if a.value == b.value => {
+                    queue.push_back((a.left.as_deref(), b.right.as_deref()));
+                    queue.push_back((a.right.as_deref(), b.left.as_deref()));
+                }
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Returns a deep-copied, mirror-image tree. Does not modify `self`.
+    /// Uses an explicit stack instead of recursion.
+    pub fn inverted(&self) -> Node<T>
+    where
+        T: Clone,
+    {
+        let mut root = Node::new_with_value(self.value.clone());
+        let mut stack = vec![(self, &mut root)];
+
+        while let Some((src, dst)) = stack.pop() {
+            if let Some(src_left) = src.left.as_deref() {
+                let mirrored = Node::new_with_value(src_left.value.clone());
+                stack.push((src_left, dst.right.insert(Box::new(mirrored))));
+            }
+            if let Some(src_right) = src.right.as_deref() {
+                let mirrored = Node::new_with_value(src_right.value.clone());
+                stack.push((src_right, dst.left.insert(Box::new(mirrored))));
+            }
+        }
+
+        root
+    }
+
+    /// Returns the value of the deepest node that has both `a` and `b` in its subtree
+    /// (a node is in its own subtree). Returns `None` if either value is not in the tree.
+    /// Finds the root-to-value path for each value and compares them. Does not use recursion.
+    pub fn lowest_common_ancestor(&self, a: &T, b: &T) -> Option<&T>
+    where
+        T: PartialEq,
+    {
+        let path_a = self.path_to(a)?;
+        let path_b = self.path_to(b)?;
+
+        path_a
+            .iter()
+            .zip(path_b.iter())
+            .take_while(|(x, y)| core::ptr::eq(**x, **y))
+            .last()
+            .map(|(node, _)| &node.value)
+    }
+
+    /// Returns the sum of all node values, accumulated as `u64` to avoid overflowing `T`.
+    pub fn sum(&self) -> u64
+    where
+        T: Copy + Into<u64>,
+    {
+        self.iter_pre_order().map(|&v| v.into()).sum()
+    }
+
+    /// Returns true if some root-to-leaf path has values summing to `target`.
+    /// Uses an explicit stack of (node, running sum) instead of recursion.
+    pub fn has_path_sum(&self, target: u64) -> bool
+    where
+        T: Copy + Into<u64>,
+    {
+        let mut stack = vec![(self, self.value.into())];
+
+        while let Some((node, running_sum)) = stack.pop() {
+            if node.is_leaf() && running_sum == target {
+                return true;
+            }
+            if let Some(right) = node.right.as_deref() {
+                stack.push((right, running_sum + right.value.into()));
+            }
+            if let Some(left) = node.left.as_deref() {
+                stack.push((left, running_sum + left.value.into()));
+            }
+        }
+
+        false
+    }
+
+    /// Returns every path from the root to a leaf, left to right.
+    /// Uses an explicit stack of partial paths instead of recursion.
+    pub fn root_to_leaf_paths(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        let mut paths = vec![];
+        let mut stack = vec![(self, vec![self.value.clone()])];
+
+        while let Some((node, path)) = stack.pop() {
+            if node.is_leaf() {
+                paths.push(path);
+                continue;
+            }
+            if let Some(right) = node.right.as_deref() {
+                let mut right_path = path.clone();
+                right_path.push(right.value.clone());
+                stack.push((right, right_path));
+            }
+            if let Some(left) = node.left.as_deref() {
+                let mut left_path = path;
+                left_path.push(left.value.clone());
+                stack.push((left, left_path));
+            }
+        }
+
+        paths
+    }
+
+    /// Returns a Graphviz DOT `digraph` with one labelled vertex per node and one edge per
+    /// parent -> child link. Missing children are skipped, so no placeholder vertices are emitted.
+    /// Vertices are identified by level-order index, so duplicate values are rendered separately.
+    pub fn to_dot(&self) -> String
+    where
+        T: Display,
+    {
+        let mut vertices = String::new();
+        let mut edges = String::new();
+        let mut next_id = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back((self, next_id));
+
+        while let Some((node, id)) = queue.pop_front() {
+            vertices.push_str(&format!(
+                "    n{id} [label={:?}];\n",
+                node.value.to_string()
+            ));
+            for child in [node.left.as_deref(), node.right.as_deref()]
+                .into_iter()
+                .flatten()
+            {
+                next_id += 1;
+                edges.push_str(&format!("    n{id} -> n{next_id};\n"));
+                queue.push_back((child, next_id));
+            }
+        }
+
+        format!("digraph {{\n{vertices}{edges}}}\n")
+    }
+
+    /// Returns the `k`-th smallest value (1-indexed), assuming the tree is a binary search tree.
+    /// The in-order walk stops as soon as the value is found.
+    pub fn kth_smallest(&self, k: usize) -> Option<&T> {
+        self.iter_in_order().nth(k.checked_sub(1)?)
+    }
+
+    /// Returns the smallest value strictly greater than `value`, assuming the tree is a binary
+    /// search tree. `value` does not need to exist in the tree. Does not use recursion.
+    pub fn in_order_successor(&self, value: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut successor = None;
+        let mut current = Some(self);
+
+        // Every time we go left, the node we left from is the closest greater ancestor so far.
+        // Once past `value`, the successor is the leftmost node of the right subtree.
+        while let Some(node) = current {
+            if value < &node.value {
+                successor = Some(&node.value);
+                current = node.left.as_deref();
+            } else {
+                current = node.right.as_deref();
+            }
+        }
+
+        successor
+    }
+
+    /// Returns the smallest value, scanning the whole tree. Does not assume BST ordering.
+    /// See `min_value_bst` for an O(height) alternative.
+    pub fn min_value(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter_pre_order().min()
+    }
+
+    /// Returns the largest value, scanning the whole tree. Does not assume BST ordering.
+    /// See `max_value_bst` for an O(height) alternative.
+    pub fn max_value(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter_pre_order().max()
+    }
+
+    /// Returns the smallest value by following leftmost links, assuming the tree is a
+    /// binary search tree. O(height).
+    pub fn min_value_bst(&self) -> &T {
+        let mut current = self;
+        while let Some(left) = current.left.as_deref() {
+            current = left;
+        }
+        &current.value
+    }
+
+    /// Returns the largest value by following rightmost links, assuming the tree is a
+    /// binary search tree. O(height).
+    pub fn max_value_bst(&self) -> &T {
+        let mut current = self;
+        while let Some(right) = current.right.as_deref() {
+            current = right;
+        }
+        &current.value
+    }
+
+    /// Returns true if some node of `self`, together with all of its descendants, is structurally
+    /// equal to `candidate` (same shape and values).
+    pub fn is_subtree(&self, candidate: &Node<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.levels().flatten().any(|node| node == candidate)
+    }
+
+    /// Returns true if node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.left.is_none() && self.right.is_none()
+    }
+
+    /// Owned-value counterpart of `from_level_order`, so callers holding an iterator of values
+    /// (eg. serde) need not clone them.
+    fn from_level_order_iter<I>(values: I) -> Option<Node<T>>
+    where
+        I: IntoIterator<Item = Option<T>>,
+    {
+        let mut values = values.into_iter();
+        let mut root = Node::new_with_value(values.next()??);
+        let mut queue = VecDeque::new();
+        queue.push_back(&mut root);
+
+        while let Some(node) = queue.pop_front() {
+            let Node { left, right, .. } = node;
+
+            match values.next() {
+                Some(Some(value)) => {
+                    queue.push_back(left.insert(Box::new(Node::new_with_value(value))))
+                }
+                Some(None) => {}
+                None => break,
+            }
+            match values.next() {
+                Some(Some(value)) => {
+                    queue.push_back(right.insert(Box::new(Node::new_with_value(value))))
+                }
+                Some(None) => {}
+                None => break,
+            }
+        }
+
+        // Every slot is filled; anything left must be padding, not a node without a parent.
+        if values.any(|value| value.is_some()) {
+            return None;
+        }
+
+        Some(root)
+    }
+
+    /// Level order with `None` for absent children, as consumed by `from_level_order`.
+    /// Trailing `None`s are omitted.
+    fn level_order_with_gaps(&self) -> Vec<Option<&T>> {
+        let mut values = vec![];
+        let mut queue = VecDeque::new();
+        queue.push_back(Some(self));
+
+        while let Some(node) = queue.pop_front() {
+            match node {
+                Some(n) => {
+                    values.push(Some(&n.value));
+                    queue.push_back(n.left.as_deref());
+                    queue.push_back(n.right.as_deref());
+                }
+                None => values.push(None),
+            }
+        }
+
+        while values.last().is_some_and(Option::is_none) {
+            values.pop();
+        }
+
+        values
+    }
+
+    /// Removes the leftmost node under `slot`, splicing its right child into its place,
+    /// and returns its value. `slot` must be `Some`.
+    fn take_min_bst(slot: &mut Option<Box<Node<T>>>) -> T {
+        let mut current = slot;
+        while current.as_ref().expect("slot is some").left.is_some() {
+            current = &mut current.as_mut().expect("slot is some").left;
+        }
+        let min = current.take().expect("slot is some");
+        *current = min.right;
+        min.value
+    }
+
+    /// Returns an iterator over the nodes of each level, top to bottom. Uses breadth-first search.
+    fn levels(&self) -> LevelIter<'_, T> {
+        LevelIter {
+            queue: VecDeque::from([self]),
+        }
+    }
+
+    /// Walks the tree bottom-up (iterative post-order), calling `visit` with the left and
+    /// right subtree heights of each node. Stops early, returning false, if `visit` returns false.
+    fn visit_subtree_heights<F>(&self, mut visit: F) -> bool
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        // The `bool` marks whether a node's children have already been pushed.
+        let mut stack = vec![(self, false)];
+        // Heights of completed subtrees, in post-order.
+        let mut heights: Vec<usize> = vec![];
+
+        while let Some((node, visited)) = stack.pop() {
+            if !visited {
+                stack.push((node, true));
+                if let Some(right) = node.right.as_deref() {
+                    stack.push((right, false));
+                }
+                if let Some(left) = node.left.as_deref() {
+                    stack.push((left, false));
+                }
+                continue;
+            }
+
+            // Right subtree completed last, so its height is on top.
+            let right_height = match node.right {
+                Some(_) => heights.pop().expect("right subtree height"),
+                None => 0,
+            };
+            let left_height = match node.left {
+                Some(_) => heights.pop().expect("left subtree height"),
+                None => 0,
+            };
+
+            if !visit(left_height, right_height) {
+                return false;
+            }
+            heights.push(1 + left_height.max(right_height));
+        }
+
+        true
+    }
+
+    /// Returns nodes on the path from `self` to the first node (in pre-order) holding `value`.
+    /// Does not use recursion.
+    fn path_to(&self, value: &T) -> Option<Vec<&Node<T>>>
+    where
+        T: PartialEq,
+    {
+        let mut path = vec![];
+        // The `bool` marks whether a node's children have already been pushed.
+        let mut stack = vec![(self, false)];
+
+        while let Some((node, visited)) = stack.pop() {
+            if visited {
+                path.pop();
+                continue;
+            }
+
+            path.push(node);
+            if &node.value == value {
+                return Some(path);
+            }
+
+            stack.push((node, true));
+            if let Some(right) = node.right.as_deref() {
+                stack.push((right, false));
+            }
+            if let Some(left) = node.left.as_deref() {
+                stack.push((left, false));
+            }
+        }
+
+        None
+    }
+}
+
+struct InOrderIter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> InOrderIter<'a, T> {
+    fn push_left_branch(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_branch(node.right.as_deref());
+        Some(&node.value)
+    }
+}
+
+struct InOrderIterMut<'a, T> {
+    // Each node is split into its value and right subtree, so the left subtree
+    // can be borrowed independently while walking down.
+    stack: Vec<(&'a mut T, Option<&'a mut Node<T>>)>,
+}
+
+impl<'a, T> InOrderIterMut<'a, T> {
+    fn push_left_branch(&mut self, mut node: Option<&'a mut Node<T>>) {
+        while let Some(n) = node {
+            let Node { value, left, right } = n;
+            self.stack.push((value, right.as_deref_mut()));
+            node = left.as_deref_mut();
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrderIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, right) = self.stack.pop()?;
+        self.push_left_branch(right);
+        Some(value)
+    }
+}
+
+struct PreOrderIter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for PreOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        // Push right first so left is popped (visited) first.
+        if let Some(right) = node.right.as_deref() {
+            self.stack.push(right);
+        }
+        if let Some(left) = node.left.as_deref() {
+            self.stack.push(left);
+        }
+        Some(&node.value)
+    }
+}
+
+struct PostOrderIter<'a, T> {
+    // The `bool` marks whether a node's children have already been pushed.
+    stack: Vec<(&'a Node<T>, bool)>,
+}
+
+impl<'a, T> Iterator for PostOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, visited)) = self.stack.pop() {
+            if visited {
+                return Some(&node.value);
+            }
+            self.stack.push((node, true));
+            if let Some(right) = node.right.as_deref() {
+                self.stack.push((right, false));
+            }
+            if let Some(left) = node.left.as_deref() {
+                self.stack.push((left, false));
+            }
+        }
+        None
+    }
+}
+
+struct LevelIter<'a, T> {
+    queue: VecDeque<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for LevelIter<'a, T> {
+    type Item = Vec<&'a Node<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.queue.is_empty() {
+            return None;
+        }
+
+        // Everything currently queued belongs to the same generation (level).
+        let level: Vec<_> = self.queue.drain(..).collect();
+        for node in level.iter() {
+            if let Some(left) = node.left.as_deref() {
+                self.queue.push_back(left);
+            }
+            if let Some(right) = node.right.as_deref() {
+                self.queue.push_back(right);
+            }
         }
+
+        Some(level)
     }
 }
 
 // Used for printing.
-struct NodeDepth<'a> {
-    node: &'a Node,
+#[cfg(any(feature = "std", test))]
+struct NodeDepth<'a, T> {
+    node: &'a Node<T>,
     depth: usize,
 }
 
 /// Generates a symmetrical binary tree iteratively. Does not use recursion.
-pub fn generate_symmetrical_iteratively(num_levels: u32) -> Node {
+/// Nodes are numbered in level order, starting at 1.
+pub fn generate_symmetrical_iteratively<T>(num_levels: u32) -> Node<T>
+where
+    T: From<u32> + Default,
+{
+    generate_symmetrical_with(num_levels, |index| T::from(index as u32 + 1))
+}
+
+/// Generates a symmetrical binary tree iteratively, where `f` receives each node's
+/// level-order index (the root is 0) and returns its value. Does not use recursion.
+pub fn generate_symmetrical_with<T, F>(num_levels: u32, mut f: F) -> Node<T>
+where
+    T: Default,
+    F: FnMut(usize) -> T,
+{
     if num_levels == 0 {
         return Node::default();
     }
 
-    let mut node_count = 1;
-    let mut root = Box::new(Node::new_with_value(1));
+    let mut index = 0;
+    let mut root = Box::new(Node::new_with_value(f(index)));
     let mut curr_level = vec![&mut root];
 
     for _ in 1..num_levels {
         let mut next_level = vec![];
         for node in curr_level {
-            node_count += 1;
-            node.left = Some(Box::new(Node::new_with_value(node_count)));
-            node_count += 1;
-            node.right = Some(Box::new(Node::new_with_value(node_count)));
+            index += 1;
+            node.left = Some(Box::new(Node::new_with_value(f(index))));
+            index += 1;
+            node.right = Some(Box::new(Node::new_with_value(f(index))));
             next_level.push(node.left.as_mut().expect("just set value"));
             next_level.push(node.right.as_mut().expect("just set value"));
         }
@@ -48,9 +1161,36 @@ pub fn generate_symmetrical_iteratively(num_levels: u32) -> Node {
     *root
 }
 
+/// Recursive counterpart of `generate_symmetrical_iteratively`, producing an identical tree.
+/// Nodes are numbered in level order, starting at 1.
+pub fn generate_symmetrical_recursively<T>(num_levels: u32) -> Node<T>
+where
+    T: From<u32> + Default,
+{
+    // In level order, the children of node `n` are numbered `2n` and `2n + 1`.
+    fn generate<T: From<u32>>(number: u32, levels_left: u32) -> Node<T> {
+        let mut node = Node::new_with_value(T::from(number));
+        if levels_left > 1 {
+            node.left = Some(Box::new(generate(number * 2, levels_left - 1)));
+            node.right = Some(Box::new(generate(number * 2 + 1, levels_left - 1)));
+        }
+        node
+    }
+
+    if num_levels == 0 {
+        return Node::default();
+    }
+
+    generate(1, num_levels)
+}
+
 /// Iteratively prints a tree. Does not use recursion.
-pub fn print_iteratively(root: &Node) {
-    let mut stack: Vec<NodeDepth> = Vec::new();
+#[cfg(any(feature = "std", test))]
+pub fn print_iteratively<T>(root: &Node<T>)
+where
+    T: Display,
+{
+    let mut stack: Vec<NodeDepth<T>> = Vec::new();
     let mut current = Some(NodeDepth {
         node: root,
         depth: 1,
@@ -78,12 +1218,12 @@ pub fn print_iteratively(root: &Node) {
 
 /// Iteratively inverts a tree in place (modifying tree that is passed in).
 /// Does not use recursion.
-pub fn invert_in_place_iteratively(root: &mut Node) {
+pub fn invert_in_place_iteratively<T>(root: &mut Node<T>) {
     let mut queue = VecDeque::new();
     queue.push_back(root);
 
     while let Some(node) = queue.pop_front() {
-        std::mem::swap(&mut node.left, &mut node.right);
+        core::mem::swap(&mut node.left, &mut node.right);
 
         if let Some(ref mut left) = node.left {
             queue.push_back(left);
@@ -93,3 +1233,891 @@ pub fn invert_in_place_iteratively(root: &mut Node) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generic_string_tree() {
+        let mut root = Node::new_with_value(String::from("root"));
+        root.left = Some(Box::new(Node::new_with_value(String::from("left"))));
+        root.right = Some(Box::new(Node::new_with_value(String::from("right"))));
+        print_iteratively(&root);
+
+        invert_in_place_iteratively(&mut root);
+        assert_eq!(root.value, "root");
+        assert_eq!(root.left.as_ref().expect("some").value, "right");
+        assert_eq!(root.right.as_ref().expect("some").value, "left");
+    }
+
+    #[test]
+    fn test_generate_symmetrical_generic() {
+        let root: U32Tree = generate_symmetrical_iteratively(2);
+        assert_eq!(root.value, 1);
+        assert_eq!(root.left.as_ref().expect("some").value, 2);
+        assert_eq!(root.right.as_ref().expect("some").value, 3);
+
+        let root_u64: Node<u64> = generate_symmetrical_iteratively(1);
+        assert_eq!(root_u64.value, 1);
+        assert!(root_u64.left.is_none() && root_u64.right.is_none());
+    }
+
+    #[test]
+    fn test_insert_bst() {
+        let values = [50, 30, 70, 20, 40, 60, 80, 30];
+        let mut root = Node::new_with_value(values[0]);
+        for &v in &values[1..] {
+            root.insert_bst(v);
+        }
+
+        assert_eq!(root.left.as_ref().expect("some").value, 30);
+        assert_eq!(root.right.as_ref().expect("some").value, 70);
+
+        let mut expected = values.to_vec();
+        expected.sort();
+        assert_eq!(root.iter_in_order().copied().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_contains_bst() {
+        let mut root = Node::new_with_value(50);
+        for v in [30, 70, 20, 40, 60, 80] {
+            root.insert_bst(v);
+        }
+
+        for v in [50, 30, 70, 20, 40, 60, 80] {
+            assert!(root.contains_bst(&v));
+        }
+        for v in [0, 25, 45, 55, 65, 100] {
+            assert!(!root.contains_bst(&v));
+        }
+    }
+
+    #[test]
+    fn test_iter_in_order() {
+        let mut root = Node::new_with_value(4);
+        for v in [2, 6, 1, 3, 5, 7] {
+            root.insert_bst(v);
+        }
+        let in_order: Vec<_> = root.iter_in_order().copied().collect();
+        assert_eq!(in_order, [1, 2, 3, 4, 5, 6, 7]);
+
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let in_order: Vec<_> = tree.iter_in_order().copied().collect();
+        assert_eq!(in_order, [4, 2, 5, 1, 6, 3, 7]);
+
+        let single = Node::new_with_value('a');
+        assert_eq!(single.iter_in_order().collect::<Vec<_>>(), [&'a']);
+    }
+
+    #[test]
+    fn test_iter_pre_order() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let pre_order: Vec<_> = tree.iter_pre_order().copied().collect();
+        assert_eq!(pre_order, [1, 2, 4, 5, 3, 6, 7]);
+
+        let single = Node::new_with_value('a');
+        assert_eq!(single.iter_pre_order().collect::<Vec<_>>(), [&'a']);
+    }
+
+    #[test]
+    fn test_iter_post_order() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let post_order: Vec<_> = tree.iter_post_order().copied().collect();
+        assert_eq!(post_order, [4, 5, 2, 6, 7, 3, 1]);
+
+        let single = Node::new_with_value('a');
+        assert_eq!(single.iter_post_order().collect::<Vec<_>>(), [&'a']);
+    }
+
+    #[test]
+    fn test_level_order() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(
+            tree.level_order(),
+            vec![vec![1], vec![2, 3], vec![4, 5, 6, 7]]
+        );
+
+        let mut bst = Node::new_with_value(5);
+        for v in [3, 8, 1] {
+            bst.insert_bst(v);
+        }
+        assert_eq!(bst.level_order(), vec![vec![5], vec![3, 8], vec![1]]);
+    }
+
+    #[test]
+    fn test_height() {
+        assert_eq!(U32Tree::default().height(), 1);
+        for levels in 1..=5 {
+            let tree: U32Tree = generate_symmetrical_iteratively(levels);
+            assert_eq!(tree.height(), levels as usize);
+        }
+
+        let mut skewed = Node::new_with_value(1);
+        for v in 2..=6 {
+            skewed.insert_bst(v);
+        }
+        assert_eq!(skewed.height(), 6);
+    }
+
+    #[test]
+    fn test_count_nodes_and_leaves() {
+        for (levels, nodes, leaves) in [(1, 1, 1), (2, 3, 2), (3, 7, 4)] {
+            let tree: U32Tree = generate_symmetrical_iteratively(levels);
+            assert_eq!(tree.count_nodes(), nodes);
+            assert_eq!(tree.count_leaves(), leaves);
+        }
+
+        let mut bst = Node::new_with_value(5);
+        for v in [3, 8, 1] {
+            bst.insert_bst(v);
+        }
+        assert_eq!(bst.count_nodes(), 4);
+        assert_eq!(bst.count_leaves(), 2);
+    }
+
+    #[test]
+    fn test_is_balanced() {
+        for levels in 0..=5 {
+            let tree: U32Tree = generate_symmetrical_iteratively(levels);
+            assert!(tree.is_balanced());
+        }
+
+        let mut skewed = Node::new_with_value(1);
+        for v in 2..=3 {
+            skewed.insert_bst(v);
+        }
+        assert!(!skewed.is_balanced());
+
+        // Root is balanced by height, but its left child is not.
+        let mut root: U32Tree = generate_symmetrical_iteratively(2);
+        let left = root.left.as_mut().expect("some");
+        left.insert_bst(10);
+        left.insert_bst(11);
+        root.right.as_mut().expect("some").insert_bst(12);
+        assert!(!root.is_balanced());
+
+        let mut leaning = Node::new_with_value(2);
+        leaning.insert_bst(1);
+        assert!(leaning.is_balanced());
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        for levels in 0..=4 {
+            let tree: U32Tree = generate_symmetrical_iteratively(levels);
+            assert!(tree.is_symmetric());
+        }
+
+        let mut tree: U32Tree = generate_symmetrical_iteratively(3);
+        invert_in_place_iteratively(&mut tree);
+        assert!(tree.is_symmetric());
+        tree.left.as_mut().expect("some").insert_bst(100);
+        assert!(!tree.is_symmetric());
+
+        let mut leaning = Node::new_with_value(2);
+        leaning.insert_bst(1);
+        assert!(!leaning.is_symmetric());
+    }
+
+    #[test]
+    fn test_is_valid_bst() {
+        let mut bst = Node::new_with_value(50);
+        for v in [30, 70, 20, 40, 60, 80, 50] {
+            bst.insert_bst(v);
+        }
+        assert!(bst.is_valid_bst());
+        assert!(Node::new_with_value(1).is_valid_bst());
+
+        // 45 sits in the left subtree of the root, but is placed
+        // deep in the right subtree instead.
+        let right = bst.right.as_mut().expect("some");
+        right.left.as_mut().expect("some").left = Some(Box::new(Node::new_with_value(45)));
+        assert!(!bst.is_valid_bst());
+
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert!(!tree.is_valid_bst());
+    }
+
+    #[test]
+    fn test_inverted() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let inverted = tree.inverted();
+
+        // Original is unchanged.
+        assert_eq!(
+            tree.level_order(),
+            vec![vec![1], vec![2, 3], vec![4, 5, 6, 7]]
+        );
+
+        let mut inverted_in_place: U32Tree = generate_symmetrical_iteratively(3);
+        invert_in_place_iteratively(&mut inverted_in_place);
+        assert_eq!(inverted.level_order(), inverted_in_place.level_order());
+        assert_eq!(
+            inverted.iter_pre_order().collect::<Vec<_>>(),
+            inverted_in_place.iter_pre_order().collect::<Vec<_>>()
+        );
+
+        let mut leaning = Node::new_with_value(2);
+        leaning.insert_bst(1);
+        let leaning_inverted = leaning.inverted();
+        assert!(leaning_inverted.left.is_none());
+        assert_eq!(leaning_inverted.right.as_ref().expect("some").value, 1);
+    }
+
+    #[test]
+    fn test_from_level_order() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let flattened: Vec<_> = tree.level_order().into_iter().flatten().map(Some).collect();
+        let rebuilt = Node::from_level_order(&flattened).expect("some");
+        assert_eq!(rebuilt.level_order(), tree.level_order());
+        assert_eq!(
+            rebuilt.iter_pre_order().collect::<Vec<_>>(),
+            tree.iter_pre_order().collect::<Vec<_>>()
+        );
+
+        //       1
+        //      / \
+        //     2   3
+        //      \   \
+        //       4   5
+        let sparse =
+            Node::from_level_order(&[Some(1), Some(2), Some(3), None, Some(4), None, Some(5)])
+                .expect("some");
+        assert_eq!(sparse.level_order(), vec![vec![1], vec![2, 3], vec![4, 5]]);
+        assert!(sparse.left.as_ref().expect("some").left.is_none());
+        assert_eq!(
+            sparse
+                .left
+                .as_ref()
+                .expect("some")
+                .right
+                .as_ref()
+                .expect("some")
+                .value,
+            4
+        );
+
+        assert!(Node::<u32>::from_level_order(&[]).is_none());
+        assert!(Node::<u32>::from_level_order(&[None, Some(1)]).is_none());
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.serialize(), "1,2,3,4,5,6,7");
+        let rebuilt = U32Tree::deserialize(&tree.serialize()).expect("some");
+        assert_eq!(rebuilt.serialize(), tree.serialize());
+        assert_eq!(rebuilt.level_order(), tree.level_order());
+
+        let sparse = "1,2,3,null,4,null,5";
+        let sparse_tree = U32Tree::deserialize(sparse).expect("some");
+        assert_eq!(sparse_tree.serialize(), sparse);
+        assert_eq!(
+            U32Tree::deserialize(" 1, null ,2 ")
+                .expect("some")
+                .serialize(),
+            "1,null,2"
+        );
+
+        let strings = Node::<String>::deserialize("a,b,c").expect("some");
+        assert_eq!(strings.level_order(), vec![vec!["a"], vec!["b", "c"]]);
+
+        // Malformed input.
+        assert!(U32Tree::deserialize("").is_none());
+        assert!(U32Tree::deserialize("null").is_none());
+        assert!(U32Tree::deserialize("1,x,3").is_none());
+        assert!(U32Tree::deserialize("1,,3").is_none());
+        assert!(U32Tree::deserialize("1,-2").is_none());
+
+        // Values left over once every child slot is filled have no parent.
+        assert!(U32Tree::deserialize("1,null,null,5").is_none());
+        assert!(U32Tree::deserialize("1,2,null,null,null,6").is_none());
+        assert!(Node::from_level_order(&[Some(1), None, None, Some(5)]).is_none());
+        // Trailing nulls are only padding.
+        assert!(U32Tree::deserialize("1,null,null,null").is_some());
+    }
+
+    #[test]
+    fn test_lowest_common_ancestor() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.lowest_common_ancestor(&4, &5), Some(&2));
+        assert_eq!(tree.lowest_common_ancestor(&4, &7), Some(&1));
+        assert_eq!(tree.lowest_common_ancestor(&6, &7), Some(&3));
+        assert_eq!(tree.lowest_common_ancestor(&2, &5), Some(&2));
+        assert_eq!(tree.lowest_common_ancestor(&5, &5), Some(&5));
+        assert_eq!(tree.lowest_common_ancestor(&1, &7), Some(&1));
+        assert_eq!(tree.lowest_common_ancestor(&4, &99), None);
+    }
+
+    #[test]
+    fn test_diameter() {
+        assert_eq!(U32Tree::default().diameter(), 0);
+
+        // 4 -> 2 -> 1 -> 3 -> 7
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.diameter(), 4);
+
+        // Longest path does not pass through the root.
+        //     1
+        //    /
+        //   2
+        //  / \
+        // 3   4
+        //      \
+        //       5
+        let off_root = U32Tree::deserialize("1,2,null,3,4,null,null,null,5").expect("some");
+        assert_eq!(off_root.diameter(), 3);
+    }
+
+    #[test]
+    fn test_sum() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.sum(), 28);
+        assert_eq!(Node::new_with_value(42u32).sum(), 42);
+
+        let mut large = Node::new_with_value(u32::MAX);
+        large.insert_bst(u32::MAX);
+        assert_eq!(large.sum(), 2 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_min_max_value() {
+        let tree = U32Tree::deserialize("8,3,12,null,1,20,5").expect("some");
+        assert_eq!(tree.min_value(), Some(&1));
+        assert_eq!(tree.max_value(), Some(&20));
+
+        let single = Node::new_with_value(7);
+        assert_eq!(single.min_value(), Some(&7));
+        assert_eq!(single.max_value(), Some(&7));
+        assert_eq!(single.min_value_bst(), &7);
+        assert_eq!(single.max_value_bst(), &7);
+
+        let mut bst = Node::new_with_value(50);
+        for v in [30, 70, 20, 40, 60, 80] {
+            bst.insert_bst(v);
+        }
+        assert_eq!(bst.min_value_bst(), &20);
+        assert_eq!(bst.max_value_bst(), &80);
+        assert_eq!(bst.min_value(), Some(bst.min_value_bst()));
+        assert_eq!(bst.max_value(), Some(bst.max_value_bst()));
+    }
+
+    #[test]
+    fn test_has_path_sum() {
+        // Paths: 1+2+4=7, 1+2+5=8, 1+3+6=10, 1+3+7=11
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        for target in [7, 8, 10, 11] {
+            assert!(tree.has_path_sum(target));
+        }
+        // 1+2=3 is not a root-to-leaf path.
+        for target in [0, 3, 9, 28] {
+            assert!(!tree.has_path_sum(target));
+        }
+        assert!(Node::new_with_value(5u32).has_path_sum(5));
+    }
+
+    #[test]
+    fn test_root_to_leaf_paths() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(
+            tree.root_to_leaf_paths(),
+            vec![vec![1, 2, 4], vec![1, 2, 5], vec![1, 3, 6], vec![1, 3, 7]]
+        );
+
+        let sparse = U32Tree::deserialize("1,2,3,null,4").expect("some");
+        assert_eq!(sparse.root_to_leaf_paths(), vec![vec![1, 2, 4], vec![1, 3]]);
+
+        assert_eq!(Node::new_with_value(9).root_to_leaf_paths(), vec![vec![9]]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let dot = tree.to_dot();
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches(" -> ").count(), 6);
+        assert!(dot.contains("    n0 [label=\"1\"];\n"));
+        assert!(dot.contains("    n0 -> n1;\n"));
+        assert!(dot.contains("    n2 -> n6;\n"));
+
+        let sparse = U32Tree::deserialize("1,null,2").expect("some");
+        assert_eq!(
+            sparse.to_dot(),
+            "digraph {\n    n0 [label=\"1\"];\n    n1 [label=\"2\"];\n    n0 -> n1;\n}\n"
+        );
+
+        let quoted = Node::new_with_value(String::from("say \"hi\""));
+        assert!(quoted.to_dot().contains(r#"[label="say \"hi\""]"#));
+    }
+
+    #[test]
+    fn test_clone_and_equality() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let mut clone = tree.clone();
+        assert_eq!(tree, clone);
+
+        clone.right.as_mut().expect("some").value = 99;
+        assert_ne!(tree, clone);
+        assert_eq!(
+            tree.level_order(),
+            vec![vec![1], vec![2, 3], vec![4, 5, 6, 7]]
+        );
+
+        // Same values, different shape.
+        let a = U32Tree::deserialize("1,2").expect("some");
+        let b = U32Tree::deserialize("1,null,2").expect("some");
+        assert_ne!(a, b);
+
+        let mut inverted = tree.clone();
+        invert_in_place_iteratively(&mut inverted);
+        assert_eq!(tree.inverted(), inverted);
+
+        let sparse = U32Tree::deserialize("1,2,3,null,4,null,5").expect("some");
+        assert_eq!(U32Tree::deserialize(&sparse.serialize()), Some(sparse));
+    }
+
+    #[test]
+    fn test_max_width() {
+        assert_eq!(U32Tree::default().max_width(), 1);
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.max_width(), 4);
+
+        // Bottom level has 2 nodes, separated by 2 missing positions.
+        let sparse = U32Tree::deserialize("1,2,3,4,null,null,5").expect("some");
+        assert_eq!(sparse.max_width(), 2);
+
+        let mut skewed = Node::new_with_value(1);
+        for v in 2..=5 {
+            skewed.insert_bst(v);
+        }
+        assert_eq!(skewed.max_width(), 1);
+    }
+
+    #[test]
+    fn test_right_side_view() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.right_side_view(), [1, 3, 7]);
+
+        // Left-skewed: every node is visible from the right.
+        let mut skewed = Node::new_with_value(5);
+        for v in [4, 3, 2, 1] {
+            skewed.insert_bst(v);
+        }
+        assert_eq!(skewed.right_side_view(), [5, 4, 3, 2, 1]);
+
+        // Deeper left branch shows through below a shorter right branch.
+        let sparse = U32Tree::deserialize("1,2,3,4").expect("some");
+        assert_eq!(sparse.right_side_view(), [1, 3, 4]);
+    }
+
+    #[test]
+    fn test_from_sorted_slice() {
+        let values = [1, 2, 3, 4, 5, 6, 7];
+        let tree = Node::from_sorted_slice(&values).expect("some");
+        assert!(tree.is_valid_bst());
+        assert!(tree.is_balanced());
+        assert_eq!(tree.height(), 3);
+        assert_eq!(
+            tree.level_order(),
+            vec![vec![4], vec![2, 6], vec![1, 3, 5, 7]]
+        );
+
+        for len in 1..=20 {
+            let values: Vec<_> = (0..len).collect();
+            let tree = Node::from_sorted_slice(&values).expect("some");
+            assert!(tree.is_valid_bst());
+            assert!(tree.is_balanced());
+            assert_eq!(tree.count_nodes(), len);
+            assert_eq!(tree.iter_in_order().copied().collect::<Vec<_>>(), values);
+        }
+
+        assert!(Node::<u32>::from_sorted_slice(&[]).is_none());
+    }
+
+    #[test]
+    fn test_delete_bst() {
+        //        50
+        //      /    \
+        //    30      70
+        //   /  \    /  \
+        //  20  40  60   80
+        //        \
+        //        45
+        let build = || {
+            let mut bst = Node::new_with_value(50);
+            for v in [30, 70, 20, 40, 60, 80, 45] {
+                bst.insert_bst(v);
+            }
+            Some(Box::new(bst))
+        };
+        let in_order = |root: &Option<Box<U32Tree>>| {
+            root.as_ref()
+                .expect("some")
+                .iter_in_order()
+                .copied()
+                .collect::<Vec<_>>()
+        };
+
+        // Leaf
+        let root = Node::delete_bst(build(), &20);
+        assert!(root.as_ref().expect("some").is_valid_bst());
+        assert_eq!(in_order(&root), [30, 40, 45, 50, 60, 70, 80]);
+
+        // Single child
+        let root = Node::delete_bst(build(), &40);
+        assert!(root.as_ref().expect("some").is_valid_bst());
+        assert_eq!(in_order(&root), [20, 30, 45, 50, 60, 70, 80]);
+        assert_eq!(
+            root.as_ref().expect("some").level_order()[2],
+            [20, 45, 60, 80]
+        );
+
+        // Two children
+        let root = Node::delete_bst(build(), &30);
+        assert!(root.as_ref().expect("some").is_valid_bst());
+        assert_eq!(in_order(&root), [20, 40, 45, 50, 60, 70, 80]);
+        assert_eq!(root.as_ref().expect("some").level_order()[1], [40, 70]);
+
+        // Root with two children
+        let root = Node::delete_bst(build(), &50);
+        assert!(root.as_ref().expect("some").is_valid_bst());
+        assert_eq!(root.as_ref().expect("some").value, 60);
+        assert_eq!(in_order(&root), [20, 30, 40, 45, 60, 70, 80]);
+
+        // Missing value leaves tree unchanged
+        assert_eq!(Node::delete_bst(build(), &99), build());
+
+        // Deleting the only node empties the tree
+        let single = Some(Box::new(Node::new_with_value(1)));
+        assert!(Node::delete_bst(single, &1).is_none());
+        assert!(Node::<u32>::delete_bst(None, &1).is_none());
+    }
+
+    #[test]
+    fn test_flatten_to_linked_list() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let list = tree.flatten_to_linked_list();
+        assert_eq!(list.len(), 7);
+        assert!(list.iter().eq(tree.iter_pre_order()));
+        assert_eq!(list.head(), Some(&1));
+        assert_eq!(list.tail(), Some(&7));
+
+        let single = Node::new_with_value("a").flatten_to_linked_list();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.head(), Some(&"a"));
+    }
+
+    #[test]
+    fn test_kth_smallest() {
+        let mut bst = Node::new_with_value(50);
+        for v in [30, 70, 20, 40, 60, 80] {
+            bst.insert_bst(v);
+        }
+        assert_eq!(bst.kth_smallest(1), Some(&20));
+        assert_eq!(bst.kth_smallest(4), Some(&50));
+        assert_eq!(bst.kth_smallest(7), Some(&80));
+        assert_eq!(bst.kth_smallest(0), None);
+        assert_eq!(bst.kth_smallest(8), None);
+    }
+
+    #[test]
+    fn test_in_order_successor() {
+        let mut bst = Node::new_with_value(50);
+        for v in [30, 70, 20, 40, 60, 80, 45] {
+            bst.insert_bst(v);
+        }
+        // Successor in right subtree.
+        assert_eq!(bst.in_order_successor(&30), Some(&40));
+        assert_eq!(bst.in_order_successor(&50), Some(&60));
+        // Successor is an ancestor.
+        assert_eq!(bst.in_order_successor(&45), Some(&50));
+        assert_eq!(bst.in_order_successor(&20), Some(&30));
+        // Maximum has no successor.
+        assert_eq!(bst.in_order_successor(&80), None);
+        // Value not in tree.
+        assert_eq!(bst.in_order_successor(&55), Some(&60));
+        assert_eq!(bst.in_order_successor(&0), Some(&20));
+    }
+
+    #[test]
+    fn test_zigzag_level_order() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(
+            tree.zigzag_level_order(),
+            vec![vec![1], vec![3, 2], vec![4, 5, 6, 7]]
+        );
+
+        let tree: U32Tree = generate_symmetrical_iteratively(4);
+        assert_eq!(
+            tree.zigzag_level_order()[3],
+            (8..=15).rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_vertical_order() {
+        //        1
+        //      /   \
+        //     2     3
+        //    / \   / \
+        //   4   5 6   7
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(
+            tree.vertical_order(),
+            vec![vec![4], vec![2], vec![1, 5, 6], vec![3], vec![7]]
+        );
+
+        assert_eq!(Node::new_with_value(1).vertical_order(), vec![vec![1]]);
+    }
+
+    #[test]
+    fn test_is_subtree() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let right = U32Tree::deserialize("3,6,7").expect("some");
+        assert!(tree.is_subtree(&right));
+        assert!(tree.is_subtree(&tree));
+        assert!(tree.is_subtree(&Node::new_with_value(5)));
+
+        // Modified value.
+        let modified = U32Tree::deserialize("3,6,8").expect("some");
+        assert!(!tree.is_subtree(&modified));
+        // Matching values, but missing descendants.
+        let partial = U32Tree::deserialize("3,6").expect("some");
+        assert!(!tree.is_subtree(&partial));
+        assert!(!tree.is_subtree(&Node::new_with_value(2)));
+    }
+
+    #[test]
+    fn test_iter_in_order_mut() {
+        let mut bst = Node::new_with_value(4);
+        for v in [2, 6, 1, 3, 5, 7] {
+            bst.insert_bst(v);
+        }
+        bst.iter_in_order_mut().for_each(|v| *v *= 2);
+        let doubled: Vec<_> = bst.iter_in_order().copied().collect();
+        assert_eq!(doubled, [2, 4, 6, 8, 10, 12, 14]);
+        assert!(bst.is_valid_bst());
+
+        // Visit order matches the immutable iterator.
+        let mut tree: U32Tree = generate_symmetrical_iteratively(3);
+        let mut visited = vec![];
+        for (i, v) in tree.iter_in_order_mut().enumerate() {
+            visited.push(*v);
+            *v = i as u32;
+        }
+        assert_eq!(visited, [4, 2, 5, 1, 6, 3, 7]);
+        assert_eq!(
+            tree.iter_in_order().copied().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_count_at_depth() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.count_at_depth(1), 1);
+        assert_eq!(tree.count_at_depth(2), 2);
+        assert_eq!(tree.count_at_depth(3), 4);
+        assert_eq!(tree.count_at_depth(4), 0);
+        assert_eq!(tree.count_at_depth(0), 0);
+
+        let sparse = U32Tree::deserialize("1,2,3,null,4").expect("some");
+        assert_eq!(sparse.count_at_depth(3), 1);
+    }
+
+    #[test]
+    fn test_is_mirror_of() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let mut inverted = tree.clone();
+        invert_in_place_iteratively(&mut inverted);
+        assert!(tree.is_mirror_of(&inverted));
+        assert!(inverted.is_mirror_of(&tree));
+        assert!(tree.is_mirror_of(&tree.inverted()));
+
+        // Structurally symmetric, but values are not mirrored.
+        assert!(!tree.is_mirror_of(&tree));
+
+        // Same shape, one value differs.
+        inverted.left.as_mut().expect("some").value = 99;
+        assert!(!tree.is_mirror_of(&inverted));
+
+        let sparse = U32Tree::deserialize("1,2,null,3").expect("some");
+        assert!(sparse.is_mirror_of(&sparse.inverted()));
+        assert!(!sparse.is_mirror_of(&sparse));
+    }
+
+    #[test]
+    fn test_min_depth() {
+        assert_eq!(U32Tree::default().min_depth(), 1);
+
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.min_depth(), 3);
+        assert_eq!(tree.min_depth(), tree.height());
+
+        let mut skewed = Node::new_with_value(1);
+        for v in 2..=5 {
+            skewed.insert_bst(v);
+        }
+        assert_eq!(skewed.min_depth(), 5);
+
+        let lopsided = U32Tree::deserialize("1,2,3,4,null,null,null,5").expect("some");
+        assert_eq!(lopsided.min_depth(), 2);
+        assert_eq!(lopsided.height(), 4);
+    }
+
+    #[test]
+    fn test_generate_symmetrical_recursively() {
+        for levels in 0..=4 {
+            let iterative: U32Tree = generate_symmetrical_iteratively(levels);
+            let recursive: U32Tree = generate_symmetrical_recursively(levels);
+            assert_eq!(iterative, recursive);
+        }
+    }
+
+    #[test]
+    fn test_generate_symmetrical_with() {
+        let tree = generate_symmetrical_with(3, |index| index * 10);
+        assert_eq!(
+            tree.level_order(),
+            vec![vec![0], vec![10, 20], vec![30, 40, 50, 60]]
+        );
+
+        let squares = generate_symmetrical_with(2, |index| (index * index) as u64);
+        assert_eq!(squares.level_order(), vec![vec![0], vec![1, 4]]);
+
+        let mut calls = 0;
+        let labels = generate_symmetrical_with(4, |index| {
+            calls += 1;
+            format!("n{index}")
+        });
+        assert_eq!(calls, 15);
+        assert_eq!(labels.right_side_view().last(), Some(&String::from("n14")));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let tree = Node::from_level_order(&[Some(1), Some(2), Some(3), None, Some(4)])
+            .expect("root present");
+        let json = serde_json::to_string(&tree).expect("serializes");
+        assert_eq!(json, "[1,2,3,null,4]");
+
+        let restored: Node<i32> = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(restored, tree);
+
+        let tree = generate_symmetrical_with(4, |i| format!("n{i}"));
+        let json = serde_json::to_string(&tree).expect("serializes");
+        let restored: Node<String> = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(restored, tree);
+
+        assert!(serde_json::from_str::<Node<i32>>("[]").is_err());
+        assert!(serde_json::from_str::<Node<i32>>("[null,1]").is_err());
+    }
+
+    #[test]
+    fn test_boundary_traversal() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        assert_eq!(tree.boundary_traversal(), [1, 2, 4, 5, 6, 7, 3]);
+
+        let tree: U32Tree = generate_symmetrical_iteratively(4);
+        assert_eq!(
+            tree.boundary_traversal(),
+            [1, 2, 4, 8, 9, 10, 11, 12, 13, 14, 15, 7, 3]
+        );
+
+        // No left subtree: the boundary runs straight from the root to the leaves.
+        let right_only = U32Tree::deserialize("1,null,2,3,4").expect("some");
+        assert_eq!(right_only.boundary_traversal(), [1, 3, 4, 2]);
+
+        // Left boundary falls back to a right child when there is no left child.
+        let zigzag = U32Tree::deserialize("1,2,3,null,4,null,null,5,6").expect("some");
+        assert_eq!(zigzag.boundary_traversal(), [1, 2, 4, 5, 6, 3]);
+
+        assert_eq!(Node::new_with_value(1).boundary_traversal(), [1]);
+    }
+
+    #[test]
+    fn test_is_complete() {
+        for levels in 1..=5 {
+            let tree: U32Tree = generate_symmetrical_iteratively(levels);
+            assert!(tree.is_complete());
+        }
+
+        // Last level filled from the left.
+        assert!(U32Tree::deserialize("1,2,3,4").expect("some").is_complete());
+        assert!(
+            U32Tree::deserialize("1,2,3,4,5,6")
+                .expect("some")
+                .is_complete()
+        );
+
+        // Gap before a present node on the last level.
+        assert!(
+            !U32Tree::deserialize("1,2,3,null,5")
+                .expect("some")
+                .is_complete()
+        );
+        assert!(
+            !U32Tree::deserialize("1,2,3,4,5,null,7")
+                .expect("some")
+                .is_complete()
+        );
+
+        // Hand-built: a missing left child with a present right child.
+        let mut incomplete = Node::new_with_value(1);
+        incomplete.right = Some(Box::new(Node::new_with_value(2)));
+        assert!(!incomplete.is_complete());
+
+        // Full levels, but a node below a non-full level.
+        assert!(
+            !U32Tree::deserialize("1,2,null,3")
+                .expect("some")
+                .is_complete()
+        );
+    }
+
+    #[test]
+    fn test_to_array() {
+        let tree: U32Tree = generate_symmetrical_iteratively(3);
+        let array = tree.to_array();
+        assert_eq!(array, (1..=7).map(Some).collect::<Vec<_>>());
+        assert_eq!(Node::from_level_order(&array).expect("some"), tree);
+
+        let complete = U32Tree::deserialize("1,2,3,4,5").expect("some");
+        let array = complete.to_array();
+        assert_eq!(array, [Some(1), Some(2), Some(3), Some(4), Some(5)]);
+        assert_eq!(Node::from_level_order(&array).expect("some"), complete);
+
+        // Gaps keep heap indexing: 4 is the left child of 3, at index 2 * 2 + 1.
+        let sparse = U32Tree::deserialize("1,null,3,4").expect("some");
+        assert_eq!(
+            sparse.to_array(),
+            [Some(1), None, Some(3), None, None, Some(4)]
+        );
+    }
+
+    #[test]
+    fn test_to_array_skewed() {
+        // A right-skewed tree of height h puts its last node at index 2^h - 2.
+        let mut short = Node::new_with_value(0);
+        for value in 1..4 {
+            short.insert_bst(value);
+        }
+        let array = short.to_array();
+        assert_eq!(array.len(), 15);
+        assert_eq!(array.iter().flatten().count(), 4);
+        assert_eq!(array[14], Some(3));
+
+        assert_eq!(short.try_to_array(), Some(array));
+
+        // At height 70 the deepest index no longer fits in usize.
+        let mut tall = Node::new_with_value(0);
+        for value in 1..70 {
+            tall.insert_bst(value);
+        }
+        assert_eq!(tall.try_to_array(), None);
+    }
+}
@@ -1,3 +1,7 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 pub mod binary_search;
 pub mod binary_tree;
 pub mod heap;
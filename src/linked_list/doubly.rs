@@ -0,0 +1,598 @@
+//! Doubly linked list.
+//!
+//! Nodes are heap allocated via `Box` and linked with raw `NonNull` pointers in both
+//! directions. `Rc<RefCell<...>>` would keep everything in safe code, but it cannot hand out
+//! plain `&T`/`&mut T` from iterators and adds refcount + borrow-flag overhead to every link.
+//! Instead the list owns every node (each one is created with `Box::leak` and freed with
+//! `Box::from_raw` exactly once), and all `unsafe` stays inside this module behind a safe API.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ptr::NonNull,
+};
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+pub struct DoublyLinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    /// Tells the compiler we own `Node<T>`s (drop check, variance).
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: the list uniquely owns its nodes, exactly like `Box<Node<T>>` would.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new(head: T) -> Self {
+        let mut this = Self::empty();
+        this.push_front(head);
+        this
+    }
+
+    /// Creates a list without any elements.
+    pub fn empty() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns reference to head.
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: `head` points to a live node owned by `self`.
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns mutable reference to head.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `head` points to a live node owned by `self`, and `&mut self` is unique.
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Returns reference to tail.
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: `tail` points to a live node owned by `self`.
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns mutable reference to tail.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `tail` points to a live node owned by `self`, and `&mut self` is unique.
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Inserts a value at the head in O(1).
+    pub fn push_front(&mut self, value: T) {
+        let node = Node::new(value);
+        // SAFETY: `node` was just allocated; `self.head` (if any) is a live node owned by `self`.
+        unsafe {
+            (*node.as_ptr()).next = self.head;
+            match self.head {
+                Some(head) => (*head.as_ptr()).prev = Some(node),
+                None => self.tail = Some(node),
+            }
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    /// Inserts a value at the tail in O(1).
+    pub fn push_back(&mut self, value: T) {
+        let node = Node::new(value);
+        // SAFETY: `node` was just allocated; `self.tail` (if any) is a live node owned by `self`.
+        unsafe {
+            (*node.as_ptr()).prev = self.tail;
+            match self.tail {
+                Some(tail) => (*tail.as_ptr()).next = Some(node),
+                None => self.head = Some(node),
+            }
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Removes and returns head in O(1).
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            // SAFETY: `head` came from `Node::new` and is unlinked here, so it is freed once.
+            let node = unsafe { Box::from_raw(head.as_ptr()) };
+            self.head = node.next;
+            match self.head {
+                // SAFETY: the new head is a live node owned by `self`.
+                Some(new_head) => unsafe { (*new_head.as_ptr()).prev = None },
+                None => self.tail = None,
+            }
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Removes and returns tail in O(1).
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| {
+            // SAFETY: `tail` came from `Node::new` and is unlinked here, so it is freed once.
+            let node = unsafe { Box::from_raw(tail.as_ptr()) };
+            self.tail = node.prev;
+            match self.tail {
+                // SAFETY: the new tail is a live node owned by `self`.
+                Some(new_tail) => unsafe { (*new_tail.as_ptr()).next = None },
+                None => self.head = None,
+            }
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Double-ended iterator over references, from head to tail (use `.rev()` for tail to head).
+    pub fn iter(&self) -> DoublyLinkedListIter<'_, T> {
+        DoublyLinkedListIter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Double-ended iterator over mutable references.
+    pub fn iter_mut(&mut self) -> DoublyLinkedListIterMut<'_, T> {
+        DoublyLinkedListIterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Drops all nodes, leaving an empty list.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Debug for DoublyLinkedList<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DoublyLinkedList")
+            .field("nodes", &self.iter().collect::<Vec<_>>())
+            .field("size", &self.len)
+            .finish()
+    }
+}
+
+impl<T> Display for DoublyLinkedList<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "DoublyLinkedList {{ ")?;
+        for (i, value) in self.iter().enumerate() {
+            if i < self.len - 1 {
+                write!(f, "{value} <-> ")?;
+            } else {
+                write!(f, "{value}")?;
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+impl<T> PartialEq for DoublyLinkedList<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for DoublyLinkedList<T> where T: Eq {}
+
+/// Hashes length, then each element in order, so lists that are equal hash equally.
+impl<T> Hash for DoublyLinkedList<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T> Clone for DoublyLinkedList<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut this = Self::empty();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+/// Consuming iteration.
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = DoublyLinkedListIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DoublyLinkedListIntoIter { list: self }
+    }
+}
+
+/// Immutable borrowing iteration.
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = DoublyLinkedListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutable borrowing iteration.
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = DoublyLinkedListIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/* ============================================================================================ */
+/* ==================================== Iterators ============================================= */
+/* ============================================================================================ */
+
+/// `len` counts the elements not yet yielded from either end, so the two cursors never cross.
+pub struct DoublyLinkedListIter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for DoublyLinkedListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            self.len -= 1;
+            // SAFETY: the node is live for `'a`, since the list is borrowed for `'a`.
+            let node = unsafe { &*node.as_ptr() };
+            self.head = node.next;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for DoublyLinkedListIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            self.len -= 1;
+            // SAFETY: the node is live for `'a`, since the list is borrowed for `'a`.
+            let node = unsafe { &*node.as_ptr() };
+            self.tail = node.prev;
+            &node.value
+        })
+    }
+}
+
+pub struct DoublyLinkedListIterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for DoublyLinkedListIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            self.len -= 1;
+            // SAFETY: the list is mutably borrowed for `'a` and each node is yielded only once.
+            let node = unsafe { &mut *node.as_ptr() };
+            self.head = node.next;
+            &mut node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for DoublyLinkedListIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            self.len -= 1;
+            // SAFETY: the list is mutably borrowed for `'a` and each node is yielded only once.
+            let node = unsafe { &mut *node.as_ptr() };
+            self.tail = node.prev;
+            &mut node.value
+        })
+    }
+}
+
+pub struct DoublyLinkedListIntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+/// Consuming iterator
+impl<T> Iterator for DoublyLinkedListIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for DoublyLinkedListIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+/* ============================================================================================ */
+/* ==================================== Node ================================================== */
+/* ============================================================================================ */
+
+struct Node<T> {
+    value: T,
+    prev: Link<T>,
+    next: Link<T>,
+}
+
+impl<T> Node<T> {
+    /// Allocates an unlinked node. The caller takes ownership and must free it with `Box::from_raw`.
+    fn new(value: T) -> NonNull<Self> {
+        let node = Box::new(Self {
+            value,
+            prev: None,
+            next: None,
+        });
+        NonNull::from(Box::leak(node))
+    }
+}
+
+/* ============================================================================================ */
+/* ==================================== TESTS ================================================= */
+/* ============================================================================================ */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_push_front() {
+        let mut list = DoublyLinkedList::new(0);
+        list.push_front(1);
+        list.push_front(2);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&0));
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut list = DoublyLinkedList::new(0);
+        list.push_front(1);
+        list.push_front(2);
+        list.push_back(99);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.back(), Some(&99));
+        assert_eq!(list.to_vec(), [2, 1, 0, 99]);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list_a = DoublyLinkedList::new(0);
+        _ = list_a.pop_front();
+        assert!(list_a.is_empty());
+        assert_eq!(list_a.len(), 0);
+        assert_eq!(list_a.back(), None);
+
+        let mut list_b = DoublyLinkedList::new(0);
+        _ = list_b.pop_back();
+        assert!(list_b.is_empty());
+        assert_eq!(list_b.len(), 0);
+        assert_eq!(list_b.front(), None);
+
+        // Reusable after being emptied.
+        list_b.push_back(1);
+        assert_eq!(list_b.front(), Some(&1));
+        assert_eq!(list_b.back(), Some(&1));
+    }
+
+    #[test]
+    fn test_front_back_mut() {
+        let mut list: DoublyLinkedList<_> = (0..3).collect();
+        if let Some(front) = list.front_mut() {
+            *front = 99;
+        }
+        if let Some(back) = list.back_mut() {
+            *back = 100;
+        }
+        assert_eq!(list.to_vec(), [99, 1, 100]);
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut list: DoublyLinkedList<_> = [33, 34, 67, 22].into_iter().collect();
+        assert_eq!(list.pop_front(), Some(33));
+        assert_eq!(list.front(), Some(&34));
+        assert_eq!(list.back(), Some(&22));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut list: DoublyLinkedList<_> = [33, 34, 67, 22].into_iter().collect();
+        assert_eq!(list.pop_back(), Some(22));
+        assert_eq!(list.back(), Some(&67));
+        assert_eq!(list.front(), Some(&33));
+        assert_eq!(list.len(), 3);
+
+        // Drain from both ends.
+        assert_eq!(list.pop_front(), Some(33));
+        assert_eq!(list.pop_back(), Some(67));
+        assert_eq!(list.pop_back(), Some(34));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_iter() {
+        let list: DoublyLinkedList<_> = (0..=5).collect();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            [5, 4, 3, 2, 1, 0]
+        );
+
+        // Alternating ends never yields an element twice.
+        let mut it = list.iter();
+        assert_eq!(it.next(), Some(&0));
+        assert_eq!(it.next_back(), Some(&5));
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&4));
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next_back(), Some(&3));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let multiplier = 10;
+        let mut list: DoublyLinkedList<_> = (0..=5).collect();
+        let expected: DoublyLinkedList<_> = (0..=5).map(|v| v * multiplier).collect();
+
+        for v in &mut list {
+            *v *= multiplier;
+        }
+        assert_eq!(list, expected);
+
+        for v in list.iter_mut().rev().take(2) {
+            *v = 0;
+        }
+        assert_eq!(list.to_vec(), [0, 10, 20, 30, 0, 0]);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list: DoublyLinkedList<_> = ["a", "b", "c"].into_iter().collect();
+        assert_eq!(
+            list.clone().into_iter().collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_equality() {
+        let mut list_a = DoublyLinkedList::new("a");
+        list_a.push_back("b");
+        let list_b = DoublyLinkedList::new("a");
+        assert_ne!(list_a, list_b);
+
+        let list_c: DoublyLinkedList<_> = ["z", "x", "q", "w"].into_iter().collect();
+        let list_d = list_c.clone();
+        assert_eq!(list_c, list_d);
+
+        let list_e: DoublyLinkedList<_> = [0, 1, 2, 3].into_iter().collect();
+        let list_f: DoublyLinkedList<_> = [0, 1, 2, 99].into_iter().collect();
+        assert_ne!(list_e, list_f);
+
+        assert_eq!(
+            DoublyLinkedList::<i32>::empty(),
+            DoublyLinkedList::default()
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list: DoublyLinkedList<_> = (0..1000).collect();
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let list: DoublyLinkedList<_> = (1..=3).collect();
+        assert_eq!(list.to_string(), "DoublyLinkedList { 1 <-> 2 <-> 3 }");
+    }
+}
@@ -1,3 +1,5 @@
+mod doubly;
 mod singly;
 
+pub use doubly::*;
 pub use singly::*;
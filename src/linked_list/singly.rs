@@ -1,6 +1,10 @@
-use std::{
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use core::{
+    cmp::Ordering,
     error::Error,
     fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut},
 };
 
 pub struct SinglyLinkedList<T> {
@@ -16,6 +20,11 @@ impl<T> SinglyLinkedList<T> {
         }
     }
 
+    /// Creates a list without any elements.
+    pub fn empty() -> Self {
+        Self { head: None, len: 0 }
+    }
+
     /// Returns reference to head.
     pub fn head(&self) -> Option<&T> {
         self.head.as_deref().map(|h| &h.value)
@@ -61,7 +70,114 @@ impl<T> SinglyLinkedList<T> {
 
     /// Removes and returns tail. Assigns node that came before popped tail as new tail.
     pub fn pop_tail(&mut self) -> Option<T> {
-        self.remove(self.len - 1)
+        self.remove(self.len.checked_sub(1)?)
+    }
+
+    /// Returns reference to the middle element, found in a single pass with slow/fast pointers.
+    /// For even lengths, returns the upper middle (eg. `3` for `[1, 2, 3, 4]`).
+    pub fn middle(&self) -> Option<&T> {
+        let mut slow = self.head.as_deref()?;
+        let mut fast = self.head.as_deref();
+
+        while let Some(fast_node) = fast
+            && let Some(fast_next) = fast_node.next.as_deref()
+        {
+            slow = slow.next.as_deref().expect("slow trails fast");
+            fast = fast_next.next.as_deref();
+        }
+
+        Some(&slow.value)
+    }
+
+    /// Returns reference to the element `n` positions before the tail (`0` is the tail).
+    /// Single pass: a lead pointer is moved `n` nodes ahead, then both advance until the lead hits the tail.
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        let mut lead = self.head.as_deref()?;
+        for _ in 0..n {
+            lead = lead.next.as_deref()?;
+        }
+
+        let mut trail = self.head.as_deref()?;
+        while let Some(lead_next) = lead.next.as_deref() {
+            lead = lead_next;
+            trail = trail.next.as_deref().expect("trail is behind lead");
+        }
+
+        Some(&trail.value)
+    }
+
+    /// Returns references to the last `n` elements, in order. Returns every element if `n >= len`.
+    /// Single pass: a window of at most `n` references slides along the list.
+    pub fn last_n(&self, n: usize) -> Vec<&T> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut window = VecDeque::with_capacity(n.min(self.len));
+
+        for value in self.iter() {
+            if window.len() == n {
+                window.pop_front();
+            }
+            window.push_back(value);
+        }
+
+        window.into()
+    }
+
+    /// Returns reference to element at `index`. Indexing is zero based.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Returns mutable reference to element at `index`. Indexing is zero based.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    /// Returns true if the list contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    /// Returns index of the first element for which `predicate` returns true.
+    /// Indexing is zero based.
+    pub fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
+    }
+
+    /// Swaps the values at indexes `i` and `j`. Nodes are not relinked. Indexing is zero based.
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), SinglyLinkedListError> {
+        let (low, high) = if i <= j { (i, j) } else { (j, i) };
+        if high >= self.len {
+            return Err(SinglyLinkedListError::IndexOutOfRange {
+                index: high,
+                len: self.len,
+            });
+        }
+        if low == high {
+            return Ok(());
+        }
+
+        let mut iter = self.iter_mut();
+        let a = iter.nth(low).expect("index within bounds");
+        let b = iter.nth(high - low - 1).expect("index within bounds");
+        core::mem::swap(a, b);
+        Ok(())
+    }
+
+    /// Returns number of elements equal to `value`.
+    pub fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(|&v| v == value).count()
     }
 
     /// Inserts new value into linked list at the front (becomes head).
@@ -73,16 +189,91 @@ impl<T> SinglyLinkedList<T> {
 
     /// Inserts new value into linked list at the end (becomes tail).
     pub fn insert_back(&mut self, value: T) {
+        *self.last_link_mut() = Some(Node::new(value));
+        self.len += 1;
+    }
+
+    /// Moves all of `other`'s nodes onto the end of `self`, consuming `other`.
+    pub fn append(&mut self, mut other: SinglyLinkedList<T>) {
+        *self.last_link_mut() = other.head.take();
+        self.len += other.len;
+    }
+
+    /// Detaches elements from index `at` onward into a new list. `self` keeps the first `at`
+    /// elements. Returns an empty list if `at >= len`. Indexing is zero based.
+    pub fn split_off(&mut self, at: usize) -> SinglyLinkedList<T> {
+        if at >= self.len {
+            return Self::empty();
+        }
+
         let mut curr = &mut self.head;
+        for _ in 0..at {
+            curr = &mut curr.as_mut().expect("index within bounds").next;
+        }
 
-        while let Some(curr_node) = curr {
-            if curr_node.next.is_none() {
-                curr_node.next = Some(Node::new(value));
-                self.len += 1;
-                return;
-            }
-            curr = &mut curr_node.next;
+        let split = SinglyLinkedList {
+            head: curr.take(),
+            len: self.len - at,
+        };
+        self.len = at;
+        split
+    }
+
+    /// Moves the first `n` elements to the end, preserving order. `n` wraps around the length.
+    /// Nodes are relinked: the old tail links to the old head and element `n` becomes the new head.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 || n % self.len == 0 {
+            return;
         }
+
+        let back = self.split_off(n % self.len);
+        let front = core::mem::replace(self, back);
+        self.append(front);
+    }
+
+    /// Keeps the first `len` elements and drops the rest. No-op if `len` is not less than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        drop(self.split_off(len));
+    }
+
+    /// Inserts new value so it ends up at `index`. Indexing is zero based.
+    /// `index == 0` inserts at the front, `index == len` inserts at the end.
+    pub fn insert_at(&mut self, index: usize, value: T) -> Result<(), SinglyLinkedListError> {
+        if index > self.len {
+            return Err(SinglyLinkedListError::IndexOutOfRange {
+                index,
+                len: self.len,
+            });
+        }
+
+        let mut curr = &mut self.head;
+        for _ in 0..index {
+            curr = &mut curr.as_mut().expect("index within bounds").next;
+        }
+
+        *curr = Some(Node::new_with_next(value, curr.take()));
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Inserts new value directly after the node at `index`. Indexing is zero based.
+    /// `index == len - 1` inserts at the end.
+    pub fn insert_after(&mut self, index: usize, value: T) -> Result<(), SinglyLinkedListError> {
+        if index >= self.len {
+            return Err(SinglyLinkedListError::IndexOutOfRange {
+                index,
+                len: self.len,
+            });
+        }
+
+        let mut curr = self.head.as_deref_mut().expect("index within bounds");
+        for _ in 0..index {
+            curr = curr.next.as_deref_mut().expect("index within bounds");
+        }
+
+        curr.next = Some(Node::new_with_next(value, curr.next.take()));
+        self.len += 1;
+        Ok(())
     }
 
     pub fn iter(&self) -> SinglyLinkedListIter<'_, T> {
@@ -97,6 +288,18 @@ impl<T> SinglyLinkedList<T> {
         }
     }
 
+    /// Yields successive chunks of up to `size` element references. The last chunk may be shorter.
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<&T>> {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        let mut iter = self.iter();
+        core::iter::from_fn(move || {
+            let chunk: Vec<_> = iter.by_ref().take(size).collect();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
     /// Indexing is zero based.
     /// If you have 3 elements in your list, the third element will be at index 2.
     pub fn remove(&mut self, index: usize) -> Option<T> {
@@ -121,6 +324,295 @@ impl<T> SinglyLinkedList<T> {
         None
     }
 
+    /// Removes and returns the element `n` positions from the end (`1` is the tail).
+    /// Returns `None` if `n` is `0` or exceeds the length.
+    /// The cached length gives the index directly, so the list is walked once.
+    pub fn remove_nth_from_end(&mut self, n: usize) -> Option<T> {
+        if n == 0 {
+            return None;
+        }
+        self.remove(self.len.checked_sub(n)?)
+    }
+
+    /// Reverses the list in place by relinking nodes. Does not reallocate.
+    pub fn reverse(&mut self) {
+        let mut prev = None;
+        let mut curr = self.head.take();
+
+        while let Some(mut curr_node) = curr {
+            curr = curr_node.next.take();
+            curr_node.next = prev;
+            prev = Some(curr_node);
+        }
+
+        self.head = prev;
+    }
+
+    /// Reverses the nodes from index `i` to `j` inclusive by relinking them. Indexing is zero based.
+    /// Locating and reversing the range is one pass. Box ownership leaves no handle on the
+    /// reversed segment's tail, so reattaching the remainder walks that segment once more.
+    pub fn reverse_between(&mut self, i: usize, j: usize) -> Result<(), SinglyLinkedListError> {
+        if i > j {
+            return Err(SinglyLinkedListError::InvalidRange { start: i, end: j });
+        }
+        if j >= self.len {
+            return Err(SinglyLinkedListError::IndexOutOfRange {
+                index: j,
+                len: self.len,
+            });
+        }
+
+        let mut curr = &mut self.head;
+        for _ in 0..i {
+            curr = &mut curr.as_mut().expect("index within bounds").next;
+        }
+
+        let count = j - i + 1;
+        let mut rest = curr.take();
+        let mut reversed = None;
+        for _ in 0..count {
+            let mut node = rest.expect("index within bounds");
+            rest = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+
+        *curr = reversed;
+        for _ in 0..count {
+            curr = &mut curr.as_mut().expect("segment has count nodes").next;
+        }
+        *curr = rest;
+        Ok(())
+    }
+
+    /// Returns a copy of all elements, in order.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Returns a new list with elements in reverse order. Does not modify `self`.
+    pub fn reversed(&self) -> SinglyLinkedList<T>
+    where
+        T: Clone,
+    {
+        let mut reversed = Self::empty();
+        for value in self.iter() {
+            reversed.insert_front(value.clone());
+        }
+        reversed
+    }
+
+    /// Returns a new list containing the result of `f` for each element, in order.
+    pub fn map<U, F>(&self, f: F) -> SinglyLinkedList<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        self.iter().map(f).collect()
+    }
+
+    /// Consumes the list, relinking its nodes into (matching, non-matching) lists.
+    /// Relative order is preserved in both.
+    pub fn partition<P>(mut self, mut predicate: P) -> (SinglyLinkedList<T>, SinglyLinkedList<T>)
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut matching = Self::empty();
+        let mut rest = Self::empty();
+        let mut matching_tail = &mut matching.head;
+        let mut rest_tail = &mut rest.head;
+        let mut curr = self.head.take();
+
+        while let Some(mut curr_node) = curr {
+            curr = curr_node.next.take();
+            if predicate(&curr_node.value) {
+                matching_tail = &mut matching_tail.insert(curr_node).next;
+                matching.len += 1;
+            } else {
+                rest_tail = &mut rest_tail.insert(curr_node).next;
+                rest.len += 1;
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// Relinks nodes so elements at even indexes come first, followed by elements at odd indexes.
+    /// Relative order within each group is preserved (eg. `[1, 2, 3, 4, 5]` becomes `[1, 3, 5, 2, 4]`).
+    pub fn odd_even(&mut self) {
+        let mut curr = self.head.take();
+        let mut evens = None;
+        let mut odd_tail = &mut self.head;
+        let mut even_tail = &mut evens;
+        let mut is_odd_position = true;
+
+        while let Some(mut curr_node) = curr {
+            curr = curr_node.next.take();
+            if is_odd_position {
+                odd_tail = &mut odd_tail.insert(curr_node).next;
+            } else {
+                even_tail = &mut even_tail.insert(curr_node).next;
+            }
+            is_odd_position = !is_odd_position;
+        }
+
+        *odd_tail = evens;
+    }
+
+    /// Sorts the list in ascending order. Stable merge sort on the nodes, O(n log n).
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    /// Sorts the list with `compare`. Stable merge sort on the nodes, O(n log n).
+    /// Nodes are relinked, values are never moved or cloned.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.head = Self::merge_sort(self.head.take(), self.len, &mut compare);
+    }
+
+    /// Returns the empty link after the tail (or `head`, if the list is empty).
+    fn last_link_mut(&mut self) -> &mut Option<Box<Node<T>>> {
+        let mut curr = &mut self.head;
+
+        while let Some(curr_node) = curr {
+            curr = &mut curr_node.next;
+        }
+
+        curr
+    }
+
+    /// Sorts the `len` nodes starting at `head`, returning the new head.
+    fn merge_sort<F>(
+        mut head: Option<Box<Node<T>>>,
+        len: usize,
+        compare: &mut F,
+    ) -> Option<Box<Node<T>>>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if len <= 1 {
+            return head;
+        }
+
+        let left_len = len / 2;
+        let mut curr = &mut head;
+        for _ in 0..left_len {
+            curr = &mut curr.as_mut().expect("within len").next;
+        }
+        let right = curr.take();
+
+        let left = Self::merge_sort(head, left_len, compare);
+        let right = Self::merge_sort(right, len - left_len, compare);
+        Self::merge(left, right, compare)
+    }
+
+    /// Merges two sorted chains of nodes into one sorted chain. Ties are taken from `left` first.
+    fn merge<F>(
+        mut left: Option<Box<Node<T>>>,
+        mut right: Option<Box<Node<T>>>,
+        compare: &mut F,
+    ) -> Option<Box<Node<T>>>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut head = None;
+        let mut tail = &mut head;
+
+        loop {
+            let take_left = match (&left, &right) {
+                (Some(l), Some(r)) => compare(&l.value, &r.value) != Ordering::Greater,
+                // One side is exhausted, so the rest of the other side is already in order.
+                _ => {
+                    *tail = left.or(right);
+                    return head;
+                }
+            };
+
+            let source = if take_left { &mut left } else { &mut right };
+            let mut node = source.take().expect("checked is_some");
+            *source = node.next.take();
+            tail = &mut tail.insert(node).next;
+        }
+    }
+
+    /// Removes the first element equal to `value`. Returns true if an element was removed.
+    pub fn remove_value(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut curr = &mut self.head;
+
+        while curr.as_ref().is_some_and(|node| node.value != *value) {
+            curr = &mut curr.as_mut().expect("checked is_some").next;
+        }
+
+        match curr.take() {
+            Some(node) => {
+                *curr = node.next;
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes every element for which `f` returns false. Preserves order of retained elements.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut curr = &mut self.head;
+
+        while let Some(curr_node) = curr.as_ref() {
+            if f(&curr_node.value) {
+                curr = &mut curr.as_mut().expect("checked is_some").next;
+            } else {
+                let removed = curr.take().expect("checked is_some");
+                *curr = removed.next;
+                self.len -= 1;
+            }
+        }
+    }
+
+    /// Removes consecutive equal elements, keeping the first of each run.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut curr = self.head.as_deref_mut();
+
+        while let Some(curr_node) = curr {
+            while curr_node
+                .next
+                .as_ref()
+                .is_some_and(|next| next.value == curr_node.value)
+            {
+                let removed = curr_node.next.take().expect("checked is_some");
+                curr_node.next = removed.next;
+                self.len -= 1;
+            }
+            curr = curr_node.next.as_deref_mut();
+        }
+    }
+
+    /// Drops all nodes, leaving an empty list. Nodes are unlinked one at a time,
+    /// so dropping a long list does not recurse through nested `Box` destructors.
+    pub fn clear(&mut self) {
+        let mut curr = self.head.take();
+        while let Some(mut curr_node) = curr {
+            curr = curr_node.next.take();
+        }
+        self.len = 0;
+    }
+
     pub fn is_empty(&self) -> bool {
         self.head.is_none()
     }
@@ -130,11 +622,47 @@ impl<T> SinglyLinkedList<T> {
     }
 }
 
+/// Consumes two sorted lists and relinks their nodes into one sorted list.
+/// Ties are taken from `a` first.
+pub fn merge_sorted<T: Ord>(
+    mut a: SinglyLinkedList<T>,
+    mut b: SinglyLinkedList<T>,
+) -> SinglyLinkedList<T> {
+    SinglyLinkedList {
+        len: a.len + b.len,
+        head: SinglyLinkedList::merge(a.head.take(), b.head.take(), &mut |x: &T, y: &T| x.cmp(y)),
+    }
+}
+
+/// Returns the first element of the longest common suffix of `a` and `b`, borrowed from `a`.
+/// Nodes are owned by their `Box`es, so two lists can never share nodes; "intersecting" here
+/// means the lists end with equal values, compared from the tails backward.
+pub fn intersection<'a, T: PartialEq>(
+    a: &'a SinglyLinkedList<T>,
+    b: &'a SinglyLinkedList<T>,
+) -> Option<&'a T> {
+    // Skip the longer list's extra prefix so both walks end on the tails together.
+    let mut a_iter = a.iter().skip(a.len.saturating_sub(b.len));
+    let b_iter = b.iter().skip(b.len.saturating_sub(a.len));
+    let mut start = None;
+
+    for value_b in b_iter {
+        let value_a = a_iter.next().expect("aligned to same length");
+        if value_a != value_b {
+            start = None;
+        } else if start.is_none() {
+            start = Some(value_a);
+        }
+    }
+
+    start
+}
+
 impl<T> Debug for SinglyLinkedList<T>
 where
     T: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("LinkedList")
             .field("head", &self.head)
             .field("size", &self.len)
@@ -146,7 +674,7 @@ impl<T> Display for SinglyLinkedList<T>
 where
     T: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "LinkedList {{ ")?;
         let mut next = self.head.as_ref();
         while let Some(next_node) = next {
@@ -188,6 +716,57 @@ where
 
 impl<T> Eq for SinglyLinkedList<T> where T: Eq {}
 
+impl<T> Drop for SinglyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Hashes length, then each element in order, so lists that are equal hash equally.
+impl<T> Hash for SinglyLinkedList<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+/// Panics if `index` is out of range.
+impl<T> Index<usize> for SinglyLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+/// Panics if `index` is out of range.
+impl<T> IndexMut<usize> for SinglyLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+/// Deep copy. Nodes are copied iteratively, so long lists do not recurse through `Box::clone`.
+impl<T> Clone for SinglyLinkedList<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
 impl<T> TryFrom<&[T]> for SinglyLinkedList<T>
 where
     T: Clone,
@@ -260,6 +839,58 @@ where
     }
 }
 
+/// Consumes the list!
+impl<T> From<SinglyLinkedList<T>> for Vec<T> {
+    fn from(list: SinglyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> FromIterator<T> for SinglyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut this = Self::empty();
+        this.extend(iter);
+        this
+    }
+}
+
+impl<T> Extend<T> for SinglyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Walk to the end once, then keep a cursor on the last link so each push is O(1).
+        let mut tail = &mut self.head;
+        while let Some(tail_node) = tail {
+            tail = &mut tail_node.next;
+        }
+
+        for value in iter {
+            tail = &mut tail.insert(Node::new(value)).next;
+            self.len += 1;
+        }
+    }
+}
+
+/// Serialized as a plain sequence of elements, head first.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for SinglyLinkedList<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// An empty sequence deserializes to an empty list.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for SinglyLinkedList<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
 /// Consuming iteration.
 impl<T> IntoIterator for SinglyLinkedList<T> {
     type Item = T;
@@ -361,7 +992,7 @@ impl<T> Debug for Node<T>
 where
     T: Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Node")
             .field("value", &self.value)
             .field("next", &self.next)
@@ -373,7 +1004,7 @@ impl<T> Display for Node<T>
 where
     T: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Node {{ value: {},", self.value)?;
         if let Some(n) = &self.next {
             write!(f, " next: {}", n)?;
@@ -391,15 +1022,25 @@ where
 #[derive(Debug)]
 pub enum SinglyLinkedListError {
     EmptySource,
+    IndexOutOfRange { index: usize, len: usize },
+    InvalidRange { start: usize, end: usize },
 }
 
 impl Display for SinglyLinkedListError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             SinglyLinkedListError::EmptySource => write!(
                 f,
                 "SinglyLinkedListError::EmptySource(source must contain at least one element)"
             ),
+            SinglyLinkedListError::IndexOutOfRange { index, len } => write!(
+                f,
+                "SinglyLinkedListError::IndexOutOfRange(index {index} is out of range for length {len})"
+            ),
+            SinglyLinkedListError::InvalidRange { start, end } => write!(
+                f,
+                "SinglyLinkedListError::InvalidRange(start {start} is greater than end {end})"
+            ),
         }
     }
 }
@@ -625,4 +1266,617 @@ mod test {
         assert_eq!(slice_list.tail(), Some(&"e"));
         assert_eq!(slice_list.len(), slice_data_len);
     }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+        assert_eq!(list.head(), Some(&5));
+        assert_eq!(list.tail(), Some(&1));
+        assert_eq!(list.len(), 5);
+
+        let mut single = SinglyLinkedList::new("a");
+        single.reverse();
+        assert_eq!(single.head(), Some(&"a"));
+        assert_eq!(single.tail(), Some(&"a"));
+        assert_eq!(single.len(), 1);
+
+        let mut empty = SinglyLinkedList::new(0);
+        _ = empty.pop_head();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_get() {
+        let list = SinglyLinkedList::try_from(["a", "b", "c", "d"]).expect("no errors");
+        assert_eq!(list.get(0), Some(&"a"));
+        assert_eq!(list.get(2), Some(&"c"));
+        assert_eq!(list.get(3), Some(&"d"));
+        assert_eq!(list.get(4), None);
+        assert_eq!(list.get(1000), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list = SinglyLinkedList::try_from([10, 20, 30, 40]).expect("no errors");
+        if let Some(v) = list.get_mut(2) {
+            *v = 99;
+        }
+        assert_eq!(list.get(2), Some(&99));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [10, 20, 99, 40]);
+        assert_eq!(list.get_mut(4), None);
+    }
+
+    #[test]
+    fn test_insert_at() {
+        let mut list = SinglyLinkedList::try_from([1, 3]).expect("no errors");
+
+        // Front
+        list.insert_at(0, 0).expect("in range");
+        // Middle
+        list.insert_at(2, 2).expect("in range");
+        // Tail
+        list.insert_at(4, 4).expect("in range");
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.tail(), Some(&4));
+
+        // Out of range
+        let err = list.insert_at(6, 6).expect_err("out of range");
+        assert!(matches!(
+            err,
+            SinglyLinkedListError::IndexOutOfRange { index: 6, len: 5 }
+        ));
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn test_contains() {
+        let list = SinglyLinkedList::try_from(["a", "b", "c"]).expect("no errors");
+        assert!(list.contains(&"a"));
+        assert!(list.contains(&"c"));
+        assert!(!list.contains(&"z"));
+    }
+
+    #[test]
+    fn test_position() {
+        let mut list = SinglyLinkedList::try_from([10, 20, 30, 40, 50]).expect("no errors");
+        let index = list.position(|&v| v == 30).expect("found");
+        assert_eq!(index, 2);
+        assert_eq!(list.remove(index), Some(30));
+        assert_eq!(list.position(|&v| v > 30), Some(2));
+        assert_eq!(list.position(|&v| v == 99), None);
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let list: SinglyLinkedList<_> = (1..=5).collect();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.head(), Some(&1));
+        assert_eq!(list.tail(), Some(&5));
+        assert_eq!(
+            list,
+            SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors")
+        );
+
+        let mut empty: SinglyLinkedList<i32> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty, SinglyLinkedList::empty());
+        assert_eq!(empty.pop_tail(), None);
+        assert_eq!(empty.pop_head(), None);
+
+        // An empty list can grow from either end.
+        empty.insert_back(1);
+        empty.insert_back(2);
+        empty.insert_front(0);
+        assert_eq!(empty.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(empty.len(), 3);
+    }
+
+    #[test]
+    fn test_clone() {
+        let list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        let mut clone = list.clone();
+        assert_eq!(list, clone);
+        assert_eq!(clone.len(), list.len());
+
+        clone.iter_mut().for_each(|v| *v *= 10);
+        assert_ne!(list, clone);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(clone.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list = SinglyLinkedList::try_from([5, 3, 1, 4, 2]).expect("no errors");
+        list.sort();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.tail(), Some(&5));
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+
+        // Stable: equal keys keep their relative order.
+        let mut pairs = SinglyLinkedList::try_from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')])
+            .expect("no errors");
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            pairs.iter().copied().collect::<Vec<_>>(),
+            [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]
+        );
+
+        let mut empty: SinglyLinkedList<i32> = SinglyLinkedList::empty();
+        empty.sort();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let vec_data = vec!["a", "b", "c"];
+        let list = SinglyLinkedList::try_from(vec_data.clone()).expect("no errors");
+        assert_eq!(list.to_vec(), vec_data);
+        assert!(SinglyLinkedList::<i32>::empty().to_vec().is_empty());
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list = SinglyLinkedList::try_from([1, 2]).expect("no errors");
+        list.append(SinglyLinkedList::try_from([3, 4]).expect("no errors"));
+        assert_eq!(list.to_vec(), [1, 2, 3, 4]);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.tail(), Some(&4));
+
+        list.append(SinglyLinkedList::empty());
+        assert_eq!(list.len(), 4);
+
+        let mut empty = SinglyLinkedList::empty();
+        empty.append(list);
+        assert_eq!(empty.to_vec(), [1, 2, 3, 4]);
+        assert_eq!(empty.len(), 4);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        let back = list.split_off(2);
+        assert_eq!(list.to_vec(), [1, 2]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(back.to_vec(), [3, 4, 5]);
+        assert_eq!(back.len(), 3);
+
+        let beyond = list.split_off(2);
+        assert!(beyond.is_empty());
+        assert_eq!(list.len(), 2);
+
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(all.to_vec(), [1, 2]);
+    }
+
+    #[test]
+    fn test_middle() {
+        let odd = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        assert_eq!(odd.middle(), Some(&3));
+
+        let even = SinglyLinkedList::try_from([1, 2, 3, 4]).expect("no errors");
+        assert_eq!(even.middle(), Some(&3));
+
+        assert_eq!(SinglyLinkedList::new(1).middle(), Some(&1));
+        assert_eq!(
+            SinglyLinkedList::try_from([1, 2])
+                .expect("no errors")
+                .middle(),
+            Some(&2)
+        );
+        assert_eq!(SinglyLinkedList::<i32>::empty().middle(), None);
+    }
+
+    #[test]
+    fn test_nth_from_end() {
+        let list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        assert_eq!(list.nth_from_end(0), list.tail());
+        assert_eq!(list.nth_from_end(1), Some(&4));
+        assert_eq!(list.nth_from_end(4), list.head());
+        assert_eq!(list.nth_from_end(5), None);
+        assert_eq!(SinglyLinkedList::<i32>::empty().nth_from_end(0), None);
+    }
+
+    #[test]
+    fn test_remove_value() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 2, 4]).expect("no errors");
+        assert!(list.remove_value(&2));
+        assert_eq!(list.to_vec(), [1, 3, 2, 4]);
+        assert_eq!(list.len(), 4);
+
+        assert!(list.remove_value(&4));
+        assert_eq!(list.tail(), Some(&2));
+        assert!(list.remove_value(&1));
+        assert_eq!(list.head(), Some(&3));
+        assert_eq!(list.len(), 2);
+
+        assert!(!list.remove_value(&99));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5, 6]).expect("no errors");
+        list.retain(|v| v % 2 == 0);
+        assert_eq!(list.to_vec(), [2, 4, 6]);
+        assert_eq!(list.len(), 3);
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = SinglyLinkedList::try_from([1, 1, 2, 3, 3, 3, 2]).expect("no errors");
+        list.dedup();
+        assert_eq!(list.to_vec(), [1, 2, 3, 2]);
+        assert_eq!(list.len(), 4);
+
+        let mut same = SinglyLinkedList::try_from([7, 7, 7]).expect("no errors");
+        same.dedup();
+        assert_eq!(same.to_vec(), [7]);
+        assert_eq!(same.len(), 1);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list = SinglyLinkedList::try_from([1, 2]).expect("no errors");
+        list.extend(3..=5);
+        assert_eq!(list.to_vec(), [1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.tail(), Some(&5));
+
+        let mut empty = SinglyLinkedList::empty();
+        empty.extend(vec!["a", "b"]);
+        empty.extend(std::iter::empty());
+        assert_eq!(empty.to_vec(), ["a", "b"]);
+        assert_eq!(empty.len(), 2);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        list.truncate(2);
+        assert_eq!(list.tail(), Some(&2));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.to_vec(), [1, 2]);
+
+        list.truncate(10);
+        assert_eq!(list.len(), 2);
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.tail(), None);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        list.swap(0, 4).expect("in range");
+        assert_eq!(list.to_vec(), [5, 2, 3, 4, 1]);
+        list.swap(3, 1).expect("in range");
+        assert_eq!(list.to_vec(), [5, 4, 3, 2, 1]);
+        list.swap(2, 2).expect("in range");
+        assert_eq!(list.to_vec(), [5, 4, 3, 2, 1]);
+
+        let err = list.swap(1, 5).expect_err("out of range");
+        assert!(matches!(
+            err,
+            SinglyLinkedListError::IndexOutOfRange { index: 5, len: 5 }
+        ));
+        assert_eq!(list.to_vec(), [5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_reversed() {
+        let list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        let reversed = list.reversed();
+        assert_eq!(list.to_vec(), [1, 2, 3]);
+        assert_eq!(reversed.to_vec(), [3, 2, 1]);
+        assert_eq!(reversed.len(), 3);
+
+        let mut reversed_in_place = list.clone();
+        reversed_in_place.reverse();
+        assert_eq!(reversed, reversed_in_place);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        assert_eq!(list[0], 1);
+        assert_eq!(list[2], 3);
+        list[1] = 20;
+        list[2] *= 10;
+        assert_eq!(list.to_vec(), [1, 20, 30]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_out_of_range() {
+        let list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_mut_out_of_range() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        list[3] = 4;
+    }
+
+    #[test]
+    fn test_map() {
+        let list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        let mapped = list.map(|v| format!("#{v}"));
+        assert_eq!(mapped.to_vec(), ["#1", "#2", "#3"]);
+        assert_eq!(mapped.len(), 3);
+        assert!(SinglyLinkedList::<i32>::empty().map(|v| v * 2).is_empty());
+    }
+
+    #[test]
+    fn test_count() {
+        let list = SinglyLinkedList::try_from([1, 2, 1, 3, 1, 2]).expect("no errors");
+        assert_eq!(list.count(&1), 3);
+        assert_eq!(list.count(&2), 2);
+        assert_eq!(list.count(&3), 1);
+        assert_eq!(list.count(&99), 0);
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        list.rotate_left(2);
+        assert_eq!(list.to_vec(), [3, 4, 5, 1, 2]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.tail(), Some(&2));
+
+        // Wraps around.
+        list.rotate_left(8);
+        assert_eq!(list.to_vec(), [1, 2, 3, 4, 5]);
+        list.rotate_left(5);
+        assert_eq!(list.to_vec(), [1, 2, 3, 4, 5]);
+
+        let mut empty = SinglyLinkedList::<i32>::empty();
+        empty.rotate_left(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let a = SinglyLinkedList::try_from([1, 3, 5]).expect("no errors");
+        let b = SinglyLinkedList::try_from([2, 4, 6]).expect("no errors");
+        let merged = merge_sorted(a, b);
+        assert_eq!(merged.to_vec(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(merged.len(), 6);
+        assert_eq!(merged.tail(), Some(&6));
+
+        let a = SinglyLinkedList::try_from([1, 1, 7]).expect("no errors");
+        let merged = merge_sorted(a, SinglyLinkedList::empty());
+        assert_eq!(merged.to_vec(), [1, 1, 7]);
+
+        let b = SinglyLinkedList::try_from([0, 8]).expect("no errors");
+        let merged = merge_sorted(merged, b);
+        assert_eq!(merged.to_vec(), [0, 1, 1, 7, 8]);
+        assert_eq!(merged.len(), 5);
+    }
+
+    #[test]
+    fn test_partition() {
+        let list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        let (even, odd) = list.partition(|v| v % 2 == 0);
+        assert_eq!(even.to_vec(), [2, 4]);
+        assert_eq!(even.len(), 2);
+        assert_eq!(odd.to_vec(), [1, 3, 5]);
+        assert_eq!(odd.len(), 3);
+
+        let (all, none) = odd.partition(|_| true);
+        assert_eq!(all.to_vec(), [1, 3, 5]);
+        assert!(none.is_empty());
+        assert_eq!(none.len(), 0);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(SinglyLinkedList::try_from([1, 2, 3]).expect("no errors"));
+        set.insert((1..=3).collect::<SinglyLinkedList<_>>());
+        assert_eq!(set.len(), 1);
+
+        set.insert(SinglyLinkedList::try_from([3, 2, 1]).expect("no errors"));
+        set.insert(SinglyLinkedList::empty());
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&SinglyLinkedList::try_from([3, 2, 1]).expect("no errors")));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list: SinglyLinkedList<_> = (0..1000).collect();
+        assert_eq!(list.len(), 1000);
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.head(), None);
+
+        // List is reusable after clearing.
+        list.insert_back(1);
+        assert_eq!(list.to_vec(), [1]);
+
+        // Dropping a long list must not overflow the stack.
+        let long: SinglyLinkedList<_> = (0..1_000_000).collect();
+        drop(long);
+    }
+
+    #[test]
+    fn test_insert_after() {
+        let mut list = SinglyLinkedList::try_from([1, 3]).expect("no errors");
+
+        // After head
+        list.insert_after(0, 2).expect("in range");
+        assert_eq!(list.to_vec(), [1, 2, 3]);
+        // After tail
+        list.insert_after(2, 4).expect("in range");
+        assert_eq!(list.to_vec(), [1, 2, 3, 4]);
+        assert_eq!(list.tail(), Some(&4));
+        assert_eq!(list.len(), 4);
+
+        // Out of range
+        let err = list.insert_after(4, 5).expect_err("out of range");
+        assert!(matches!(
+            err,
+            SinglyLinkedListError::IndexOutOfRange { index: 4, len: 4 }
+        ));
+        assert!(SinglyLinkedList::empty().insert_after(0, 1).is_err());
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn test_last_n() {
+        let list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        assert_eq!(list.last_n(2), [&4, &5]);
+        assert_eq!(list.last_n(1), [&5]);
+        assert_eq!(list.last_n(5), [&1, &2, &3, &4, &5]);
+        assert_eq!(list.last_n(10), [&1, &2, &3, &4, &5]);
+        assert!(list.last_n(0).is_empty());
+        assert!(SinglyLinkedList::<i32>::empty().last_n(3).is_empty());
+    }
+
+    #[test]
+    fn test_remove_nth_from_end() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        assert_eq!(list.remove_nth_from_end(2), Some(4));
+        assert_eq!(list.to_vec(), [1, 2, 3, 5]);
+        assert_eq!(list.len(), 4);
+
+        assert_eq!(list.remove_nth_from_end(1), Some(5));
+        assert_eq!(list.tail(), Some(&3));
+        assert_eq!(list.remove_nth_from_end(3), Some(1));
+        assert_eq!(list.head(), Some(&2));
+        assert_eq!(list.len(), 2);
+
+        assert_eq!(list.remove_nth_from_end(3), None);
+        assert_eq!(list.remove_nth_from_end(0), None);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_reverse_between() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        list.reverse_between(1, 3).expect("in range");
+        assert_eq!(list.to_vec(), [1, 4, 3, 2, 5]);
+        assert_eq!(list.len(), 5);
+
+        list.reverse_between(0, 4).expect("in range");
+        assert_eq!(list.to_vec(), [5, 2, 3, 4, 1]);
+        list.reverse_between(3, 4).expect("in range");
+        assert_eq!(list.to_vec(), [5, 2, 3, 1, 4]);
+        assert_eq!(list.tail(), Some(&4));
+        list.reverse_between(2, 2).expect("in range");
+        assert_eq!(list.to_vec(), [5, 2, 3, 1, 4]);
+
+        let err = list.reverse_between(3, 1).expect_err("start after end");
+        assert!(matches!(
+            err,
+            SinglyLinkedListError::InvalidRange { start: 3, end: 1 }
+        ));
+        let err = list.reverse_between(2, 5).expect_err("out of range");
+        assert!(matches!(
+            err,
+            SinglyLinkedListError::IndexOutOfRange { index: 5, len: 5 }
+        ));
+        assert_eq!(list.to_vec(), [5, 2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn test_odd_even() {
+        let mut list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        list.odd_even();
+        assert_eq!(list.to_vec(), [1, 3, 5, 2, 4]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.tail(), Some(&4));
+
+        let mut even_len = SinglyLinkedList::try_from(["a", "b", "c", "d"]).expect("no errors");
+        even_len.odd_even();
+        assert_eq!(even_len.to_vec(), ["a", "c", "b", "d"]);
+
+        let mut single = SinglyLinkedList::new(1);
+        single.odd_even();
+        assert_eq!(single.to_vec(), [1]);
+
+        let mut empty = SinglyLinkedList::<i32>::empty();
+        empty.odd_even();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_chunks() {
+        let list = SinglyLinkedList::try_from([1, 2, 3, 4, 5]).expect("no errors");
+        let chunks: Vec<_> = list.chunks(2).collect();
+        assert_eq!(chunks, [vec![&1, &2], vec![&3, &4], vec![&5]]);
+
+        assert_eq!(list.chunks(5).count(), 1);
+        assert_eq!(list.chunks(10).next(), Some(vec![&1, &2, &3, &4, &5]));
+        assert_eq!(SinglyLinkedList::<i32>::empty().chunks(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero_size() {
+        let list = SinglyLinkedList::try_from([1, 2, 3]).expect("no errors");
+        _ = list.chunks(0);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = SinglyLinkedList::try_from([1, 2, 3, 7, 8]).expect("no errors");
+        let b = SinglyLinkedList::try_from([9, 7, 8]).expect("no errors");
+        assert_eq!(intersection(&a, &b), Some(&7));
+        assert_eq!(intersection(&b, &a), Some(&7));
+
+        // Equal values before a mismatch are not part of the common suffix.
+        let c = SinglyLinkedList::try_from([7, 0, 8]).expect("no errors");
+        assert_eq!(intersection(&a, &c), Some(&8));
+
+        let d = SinglyLinkedList::try_from([7, 8, 9]).expect("no errors");
+        assert_eq!(intersection(&a, &d), None);
+        assert_eq!(intersection(&a, &SinglyLinkedList::empty()), None);
+        assert_eq!(intersection(&a, &a.clone()), Some(&1));
+    }
+
+    #[test]
+    fn test_into_vec() {
+        let vec_data = vec![1, 2, 3, 4];
+        let list = SinglyLinkedList::try_from(vec_data.clone()).expect("no errors");
+        let round_trip: Vec<_> = list.into();
+        assert_eq!(round_trip, vec_data);
+        assert!(Vec::from(SinglyLinkedList::<i32>::empty()).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let list = SinglyLinkedList::try_from(["a", "b", "c"]).expect("no errors");
+        let json = serde_json::to_string(&list).expect("serializes");
+        assert_eq!(json, r#"["a","b","c"]"#);
+
+        let restored: SinglyLinkedList<String> = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.to_vec(), ["a", "b", "c"]);
+        assert_eq!(restored, list.map(|v| v.to_string()));
+
+        let empty: SinglyLinkedList<i32> = serde_json::from_str("[]").expect("deserializes");
+        assert!(empty.is_empty());
+        assert_eq!(serde_json::to_string(&empty).expect("serializes"), "[]");
+    }
 }
@@ -1,9 +1,13 @@
-use crate::heap::Heap;
+use crate::{
+    heap::{Heap, HeapBuilder},
+    queue::Queue,
+};
 
-use std::{
+use alloc::{vec, vec::Vec};
+use core::{
     cmp::Ordering,
     fmt::{self, Debug, Display},
-    slice, vec,
+    slice,
 };
 
 pub struct PriorityQueue<T, F>
@@ -106,6 +110,25 @@ where
         }
     }
 
+    /// Drains `queue` in FIFO order and re-prioritizes its elements using `compare`.
+    pub fn from_queue(mut queue: Queue<T>, compare: F) -> Self {
+        Self {
+            heap: HeapBuilder::new(compare)
+                .extend(core::iter::from_fn(|| queue.dequeue()))
+                .build(),
+        }
+    }
+
+    /// Wraps an existing heap without rebuilding it.
+    pub fn from_heap(heap: Heap<T, F>) -> Self {
+        Self { heap }
+    }
+
+    /// Unwraps the underlying heap without rebuilding it.
+    pub fn into_heap(self) -> Heap<T, F> {
+        self.heap
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.heap.iter()
     }
@@ -446,4 +469,31 @@ mod test {
         assert_eq!(max_queue.to_sorted_vec(), vec![FooChar::new('z')]);
         assert_eq!(max_queue.size(), 1);
     }
+
+    #[test]
+    fn test_heap_round_trip() {
+        let compare = |a: &i32, b: &i32| b.cmp(a);
+        let queue = PriorityQueue::new(compare, Some(vec![50, 80, 30, 90, 60, 40, 20]));
+        let sorted = queue.to_sorted_vec();
+
+        let heap = queue.into_heap();
+        assert_eq!(heap.to_sorted_vec(), sorted);
+
+        let queue = PriorityQueue::from_heap(heap);
+        assert_eq!(queue.to_sorted_vec(), sorted);
+        assert_eq!(queue.front(), Some(&20));
+    }
+
+    #[test]
+    fn test_from_queue() {
+        let mut queue = Queue::new();
+        for v in [67, 39, 71, 22, 382, 4] {
+            queue.enqueue(v);
+        }
+
+        let min_queue = PriorityQueue::from_queue(queue, |a: &i32, b: &i32| b.cmp(a));
+        assert_eq!(min_queue.size(), 6);
+        assert_eq!(min_queue.front(), Some(&4));
+        assert_eq!(min_queue.to_sorted_vec(), [4, 22, 39, 67, 71, 382]);
+    }
 }
@@ -1,3 +1,6 @@
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::hash::{Hash, Hasher};
+
 pub struct Queue<T> {
     inbox: Vec<T>,
     outbox: Vec<T>,
@@ -32,6 +35,11 @@ impl<T> Queue<T> {
         }
     }
 
+    /// Iterates from front to back, in dequeue order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.outbox.iter().rev().chain(self.inbox.iter())
+    }
+
     pub fn len(&self) -> usize {
         self.inbox.len() + self.outbox.len()
     }
@@ -47,6 +55,135 @@ impl<T> Default for Queue<T> {
     }
 }
 
+/// Queue that keeps its elements in non-increasing order, so the maximum is always at the front.
+/// Used for sliding-window maximum: push each value entering the window, and call `pop_front`
+/// with each value leaving it. Elements are dropped from the back on `push` when they can no
+/// longer be the maximum, so every operation is O(1) amortized.
+pub struct MonotonicQueue<T: Ord> {
+    items: VecDeque<T>,
+}
+
+impl<T: Ord> MonotonicQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Pushes `value` to the back, first dropping any smaller elements from the back.
+    /// Equal elements are kept so each one can be matched by `pop_front` later.
+    pub fn push(&mut self, value: T) {
+        while self.items.back().is_some_and(|back| *back < value) {
+            self.items.pop_back();
+        }
+        self.items.push_back(value);
+    }
+
+    /// Removes the front element if it equals `leaving`, the value sliding out of the window.
+    /// If it doesn't, `leaving` was already dropped by a later `push` and nothing is removed.
+    pub fn pop_front(&mut self, leaving: &T) -> Option<T> {
+        if self.items.front() == Some(leaving) {
+            self.items.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Returns reference to the largest element.
+    pub fn max(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Number of elements still held, which may be fewer than were pushed.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T: Ord> Default for MonotonicQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the maximum of each contiguous window of `window` elements, in O(n) using a
+/// `MonotonicQueue`. Returns an empty `Vec` if `window` is 0 or larger than `data`.
+pub fn sliding_window_max<T: Ord + Clone>(data: &[T], window: usize) -> Vec<T> {
+    if window == 0 || window > data.len() {
+        return vec![];
+    }
+
+    let mut queue = MonotonicQueue::new();
+    let mut maxima = Vec::with_capacity(data.len() - window + 1);
+
+    for (i, value) in data.iter().enumerate() {
+        queue.push(value);
+        if i >= window {
+            let leaving = &data[i - window];
+            queue.pop_front(&leaving);
+        }
+        if i + 1 >= window {
+            maxima.push((*queue.max().expect("window is not empty")).clone());
+        }
+    }
+
+    maxima
+}
+
+/// Queues are equal if they hold equal elements in the same dequeue order,
+/// regardless of how those elements are split between inbox and outbox.
+impl<T> PartialEq for Queue<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for Queue<T> where T: Eq {}
+
+/// Hashes length, then each element in dequeue order, so queues that are equal hash equally.
+impl<T> Hash for Queue<T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+/// Serialized as a plain sequence in dequeue order, regardless of the inbox/outbox split.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Queue<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Queue<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self {
+            inbox: Vec::deserialize(deserializer)?,
+            outbox: vec![],
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -118,4 +255,119 @@ mod test {
 
         // ------------------------------------------------------------------------
     }
+
+    #[test]
+    fn test_iter() {
+        let mut queue = Queue::new();
+        for v in [1, 2, 3] {
+            queue.enqueue(v);
+        }
+        _ = queue.dequeue();
+        queue.enqueue(4);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut queue = Queue::new();
+        for v in [10, 20, 30] {
+            queue.enqueue(v);
+        }
+        // Split elements across outbox and inbox.
+        assert_eq!(queue.dequeue(), Some(10));
+        queue.enqueue(40);
+
+        let json = serde_json::to_string(&queue).expect("serializes");
+        assert_eq!(json, "[20,30,40]");
+
+        let mut restored: Queue<i32> = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(restored.len(), 3);
+        assert_eq!(restored.dequeue(), Some(20));
+        assert_eq!(restored.dequeue(), Some(30));
+        assert_eq!(restored.dequeue(), Some(40));
+        assert_eq!(restored.dequeue(), None);
+
+        let empty: Queue<i32> = serde_json::from_str("[]").expect("deserializes");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        // All elements in the inbox.
+        let mut queue_a = Queue::new();
+        for v in [1, 2, 3] {
+            queue_a.enqueue(v);
+        }
+
+        // Same logical sequence, split across outbox and inbox.
+        let mut queue_b = Queue::new();
+        for v in [0, 1, 2] {
+            queue_b.enqueue(v);
+        }
+        _ = queue_b.dequeue();
+        queue_b.enqueue(3);
+        assert!(queue_a == queue_b);
+
+        let mut set = HashSet::new();
+        set.insert(queue_a);
+        set.insert(queue_b);
+        assert_eq!(set.len(), 1);
+
+        set.insert(Queue::new());
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_monotonic_queue() {
+        let data = [1, 3, -1, -3, 5, 3, 6, 7];
+        let window = 3;
+        let mut queue = MonotonicQueue::new();
+        let mut maxima = vec![];
+
+        for (i, &v) in data.iter().enumerate() {
+            queue.push(v);
+            if i >= window {
+                queue.pop_front(&data[i - window]);
+            }
+            if i + 1 >= window {
+                maxima.push(*queue.max().expect("window is not empty"));
+            }
+        }
+        assert_eq!(maxima, [3, 3, 5, 5, 6, 7]);
+
+        // Equal values are kept, so each leaving duplicate is matched.
+        let mut dups = MonotonicQueue::new();
+        dups.push(2);
+        dups.push(2);
+        assert_eq!(dups.len(), 2);
+        assert_eq!(dups.pop_front(&2), Some(2));
+        assert_eq!(dups.max(), Some(&2));
+        assert_eq!(dups.pop_front(&1), None);
+        assert_eq!(dups.pop_front(&2), Some(2));
+        assert!(dups.is_empty());
+        assert_eq!(dups.max(), None);
+    }
+
+    #[test]
+    fn test_sliding_window_max() {
+        let data = [1, 3, -1, -3, 5, 3, 6, 7, 7, 2, -8, 4, 4, 0];
+        for window in 1..=data.len() {
+            let brute_force: Vec<_> = data
+                .windows(window)
+                .map(|w| *w.iter().max().expect("non-empty"))
+                .collect();
+            assert_eq!(sliding_window_max(&data, window), brute_force);
+        }
+
+        assert_eq!(sliding_window_max(&data, 3)[..6], [3, 3, 5, 5, 6, 7]);
+        assert!(sliding_window_max(&data, 0).is_empty());
+        assert!(sliding_window_max(&data, data.len() + 1).is_empty());
+        assert!(sliding_window_max::<i32>(&[], 1).is_empty());
+
+        let words = ["b", "d", "a", "c"].map(String::from);
+        assert_eq!(sliding_window_max(&words, 2), ["d", "d", "c"]);
+    }
 }
@@ -1,8 +1,27 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
 #[derive(Default, Debug)]
 pub struct Node {
     value: char,
     children: Vec<Node>,
     word: Option<String>,
+    /// Number of words stored in the subtree rooted at this node (including this node).
+    word_count: usize,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrieStats {
+    /// Number of words stored in the trie.
+    pub words: usize,
+    /// Number of nodes allocated, including the root.
+    pub nodes: usize,
+    /// Length (in chars) of the deepest path from the root.
+    pub max_depth: usize,
 }
 
 #[derive(Default, Debug)]
@@ -18,7 +37,12 @@ impl Trie {
     }
 
     pub fn insert(&mut self, word: &str) {
+        // Inserting a duplicate must not inflate subtree word counts.
+        let is_new_word = self.find_node(word).is_none_or(|n| n.word.is_none());
         let mut current_node = &mut self.root;
+        if is_new_word {
+            current_node.word_count += 1;
+        }
 
         for ch in word.chars() {
             let found_node = current_node
@@ -34,10 +58,15 @@ impl Trie {
                         value: ch,
                         children: Vec::new(),
                         word: None,
+                        word_count: 0,
                     });
                     current_node = current_node.children.last_mut().unwrap();
                 }
             }
+
+            if is_new_word {
+                current_node.word_count += 1;
+            }
         }
 
         current_node.word = Some(word.to_string());
@@ -72,6 +101,139 @@ impl Trie {
         true
     }
 
+    /// Finds all words that start with prefix and whose length (in chars) is within
+    /// `min_len..=max_len`. Branches deeper than `max_len` are never visited.
+    pub fn words_with_prefix_len(
+        &self,
+        prefix: &str,
+        min_len: usize,
+        max_len: usize,
+    ) -> Vec<String> {
+        let depth = prefix.chars().count();
+        if depth > max_len {
+            return vec![];
+        }
+
+        let Some(node) = self.find_node(prefix) else {
+            return vec![];
+        };
+
+        let mut extracted = vec![];
+        Self::extract_words_bounded(node, depth, min_len, max_len, &mut extracted);
+        extracted
+    }
+
+    /// Returns the shortest prefix of `word` that is not shared by any other stored word.
+    /// Returns the whole word if every prefix is shared (eg. `word` is a prefix of another word).
+    /// Returns `None` if `word` is not stored in the trie.
+    pub fn shortest_unique_prefix(&self, word: &str) -> Option<String> {
+        self.find_node(word)?.word.as_ref()?;
+
+        let mut cn = &self.root;
+        let mut prefix = String::new();
+
+        for ch in word.chars() {
+            cn = cn.children.iter().find(|n| n.value == ch)?;
+            prefix.push(ch);
+            if cn.word_count == 1 {
+                return Some(prefix);
+            }
+        }
+
+        Some(prefix)
+    }
+
+    /// Returns the longest stored word that is a prefix of `query` (longest-prefix match).
+    /// `query` itself counts if it is stored. Returns `None` if no stored word is a prefix.
+    pub fn longest_prefix_of(&self, query: &str) -> Option<String> {
+        let mut cn = &self.root;
+        let mut longest = cn.word.as_ref();
+
+        for ch in query.chars() {
+            let Some(next) = cn.children.iter().find(|n| n.value == ch) else {
+                break;
+            };
+            cn = next;
+            if cn.word.is_some() {
+                longest = cn.word.as_ref();
+            }
+        }
+
+        longest.cloned()
+    }
+
+    /// Prints the trie as an indented tree. See `Display` impl.
+    #[cfg(any(feature = "std", test))]
+    pub fn print_tree(&self) {
+        print!("{self}");
+    }
+
+    /// Returns total number of nodes, including the root.
+    pub fn node_count(&self) -> usize {
+        self.stats().nodes
+    }
+
+    /// Returns the number of distinct non-empty prefixes of stored words. Every node below the
+    /// root is the end of exactly one prefix, so shared prefixes are only counted once.
+    pub fn distinct_prefix_count(&self) -> usize {
+        // The root is the empty prefix.
+        self.node_count() - 1
+    }
+
+    /// Collects word count, node count and max depth in a single iterative DFS.
+    pub fn stats(&self) -> TrieStats {
+        let mut stats = TrieStats::default();
+        let mut stack = vec![(&self.root, 0)];
+
+        while let Some((node, depth)) = stack.pop() {
+            stats.nodes += 1;
+            if node.word.is_some() {
+                stats.words += 1;
+            }
+            stats.max_depth = stats.max_depth.max(depth);
+
+            for child in node.children.iter() {
+                stack.push((child, depth + 1));
+            }
+        }
+
+        stats
+    }
+
+    /// Returns the node at the end of `prefix`, if it exists.
+    fn find_node(&self, prefix: &str) -> Option<&Node> {
+        let mut cn = &self.root;
+
+        for ch in prefix.chars() {
+            cn = cn.children.iter().find(|n| n.value == ch)?;
+        }
+
+        Some(cn)
+    }
+
+    /// Extracts words starting from a given node, at `depth`, whose length is within `min_len..=max_len`.
+    fn extract_words_bounded(
+        root: &Node,
+        depth: usize,
+        min_len: usize,
+        max_len: usize,
+        extracted: &mut Vec<String>,
+    ) {
+        if let Some(word) = root.word.as_ref()
+            && depth >= min_len
+        {
+            extracted.push(word.to_string());
+        }
+
+        if depth == max_len {
+            return;
+        }
+
+        for child_node in root.children.iter() {
+            Self::extract_words_bounded(child_node, depth + 1, min_len, max_len, extracted);
+        }
+    }
+
     /// Extracts all words starting from a given node.
     fn extract_words(root: &Node) -> Vec<String> {
         let mut extracted = vec![];
@@ -89,6 +251,61 @@ impl Trie {
     }
 }
 
+/// Prints each character indented by depth. Nodes that end a word are marked with `*`.
+/// Does not use recursion.
+impl Display for Trie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stack: Vec<(&Node, usize)> =
+            self.root.children.iter().rev().map(|n| (n, 0)).collect();
+
+        while let Some((node, depth)) = stack.pop() {
+            let marker = if node.word.is_some() { "*" } else { "" };
+            writeln!(
+                f,
+                "{:indent$}{}{marker}",
+                "",
+                node.value,
+                indent = depth * 2
+            )?;
+            for child in node.children.iter().rev() {
+                stack.push((child, depth + 1));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Trie for querying words by suffix.
+/// Words are stored reversed internally, so a suffix query is a prefix query on the reversed suffix.
+#[derive(Default, Debug)]
+pub struct SuffixTrie {
+    trie: Trie,
+}
+
+impl SuffixTrie {
+    pub fn new() -> Self {
+        Self { trie: Trie::new() }
+    }
+
+    pub fn insert(&mut self, word: &str) {
+        self.trie.insert(&Self::reverse(word));
+    }
+
+    /// Finds all words that end with suffix. Returned words are in their original (un-reversed) form.
+    pub fn find_all_by_suffix(&self, suffix: &str) -> Vec<String> {
+        self.trie
+            .find_all_by_prefix(&Self::reverse(suffix))
+            .iter()
+            .map(|w| Self::reverse(w))
+            .collect()
+    }
+
+    fn reverse(s: &str) -> String {
+        s.chars().rev().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +347,168 @@ mod tests {
         assert!(!t.contains("hz"));
         assert!(t.contains("heli"));
     }
+
+    #[test]
+    fn test_stats() {
+        let empty = Trie::new();
+        // Only the root.
+        assert_eq!(empty.node_count(), 1);
+        assert_eq!(
+            empty.stats(),
+            TrieStats {
+                nodes: 1,
+                ..TrieStats::default()
+            }
+        );
+
+        // root
+        // "tea" -> t, e, a
+        // "ten" ->       n
+        // "to"  ->    o
+        // "in"  -> i, n
+        let mut t = Trie::new();
+        ["tea", "ten", "to", "in"]
+            .iter()
+            .for_each(|word| t.insert(word));
+        assert_eq!(t.node_count(), 8);
+        assert_eq!(
+            t.stats(),
+            TrieStats {
+                words: 4,
+                nodes: 8,
+                max_depth: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let empty = Trie::new();
+        assert_eq!(empty.to_string(), "");
+        empty.print_tree();
+
+        let mut t = Trie::new();
+        ["to", "tea", "in"].iter().for_each(|word| t.insert(word));
+        let expected = "t\n  o*\n  e\n    a*\ni\n  n*\n";
+        assert_eq!(t.to_string(), expected);
+        t.print_tree();
+    }
+
+    #[test]
+    fn test_shortest_unique_prefix() {
+        let mut t = Trie::new();
+        let bulk = ["astronaut", "astronomy", "microscope", "micro"];
+        bulk.iter().for_each(|word| t.insert(word));
+        // Inserting a duplicate must not affect the result.
+        t.insert("astronaut");
+
+        // "astronaut" and "astronomy" diverge after "astron".
+        assert_eq!(
+            t.shortest_unique_prefix("astronaut"),
+            Some("astrona".into())
+        );
+        assert_eq!(
+            t.shortest_unique_prefix("astronomy"),
+            Some("astrono".into())
+        );
+        assert_eq!(
+            t.shortest_unique_prefix("microscope"),
+            Some("micros".into())
+        );
+        // "micro" is a prefix of "microscope", so the whole word is returned.
+        assert_eq!(t.shortest_unique_prefix("micro"), Some("micro".into()));
+        // Not stored.
+        assert_eq!(t.shortest_unique_prefix("astro"), None);
+        assert_eq!(t.shortest_unique_prefix("zzz"), None);
+
+        let mut single = Trie::new();
+        single.insert("lantern");
+        assert_eq!(single.shortest_unique_prefix("lantern"), Some("l".into()));
+    }
+
+    #[test]
+    fn test_find_all_by_suffix() {
+        let mut t = SuffixTrie::new();
+        let bulk = [
+            "astronaut",
+            "astronomy",
+            "microscope",
+            "telescope",
+            "microchip",
+            "lantern",
+        ];
+        bulk.iter().for_each(|word| t.insert(word));
+
+        assert_eq!(t.find_all_by_suffix("microscope"), ["microscope"]);
+        let mut scopes = t.find_all_by_suffix("scope");
+        scopes.sort();
+        assert_eq!(scopes, ["microscope", "telescope"]);
+        assert_eq!(t.find_all_by_suffix("chip"), ["microchip"]);
+        assert!(t.find_all_by_suffix("zzz").is_empty());
+        assert!(t.find_all_by_suffix("xscope").is_empty());
+    }
+
+    #[test]
+    fn test_words_with_prefix_len() {
+        let mut t = Trie::new();
+        let bulk = ["hello", "help", "helicopter", "helipad", "he", "hero"];
+        bulk.iter().for_each(|word| t.insert(word));
+
+        assert_eq!(
+            t.words_with_prefix_len("he", 4, 5),
+            ["hello", "help", "hero"]
+        );
+        assert_eq!(t.words_with_prefix_len("he", 0, 2), ["he"]);
+        assert_eq!(t.words_with_prefix_len("heli", 7, 7), ["helipad"]);
+        assert_eq!(
+            t.words_with_prefix_len("h", 0, usize::MAX),
+            t.find_all_by_prefix("h")
+        );
+        // Prefix longer than `max_len`.
+        assert!(t.words_with_prefix_len("hello", 0, 3).is_empty());
+        // Unknown prefix.
+        assert!(t.words_with_prefix_len("z", 0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_distinct_prefix_count() {
+        let mut t = Trie::new();
+        assert_eq!(t.distinct_prefix_count(), 0);
+
+        // "astron" is shared: a, as, ast, astr, astro, astron.
+        // Then "aut" and "omy" add 3 each.
+        t.insert("astronaut");
+        t.insert("astronomy");
+        assert_eq!(t.distinct_prefix_count(), 6 + 3 + 3);
+
+        // Stored words and their prefixes add nothing new.
+        t.insert("astro");
+        t.insert("astronaut");
+        assert_eq!(t.distinct_prefix_count(), 12);
+
+        t.insert("at");
+        assert_eq!(t.distinct_prefix_count(), 13);
+    }
+
+    #[test]
+    fn test_longest_prefix_of() {
+        let mut t = Trie::new();
+        ["tr", "trans", "transform", "transport"]
+            .iter()
+            .for_each(|w| t.insert(w));
+
+        assert_eq!(
+            t.longest_prefix_of("transforming"),
+            Some(String::from("transform"))
+        );
+        assert_eq!(t.longest_prefix_of("transit"), Some(String::from("trans")));
+        assert_eq!(t.longest_prefix_of("trim"), Some(String::from("tr")));
+        assert_eq!(
+            t.longest_prefix_of("transport"),
+            Some(String::from("transport"))
+        );
+        assert_eq!(t.longest_prefix_of("t"), None);
+        assert_eq!(t.longest_prefix_of("lantern"), None);
+        assert_eq!(t.longest_prefix_of(""), None);
+    }
 }
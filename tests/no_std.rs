@@ -0,0 +1,44 @@
+//! Compiled as a `no_std` crate to confirm the core types are usable with only `alloc`.
+//! Run with `cargo test --no-default-features --test no_std`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+use dsa_rs::{
+    binary_tree::Node,
+    heap::HeapBuilder,
+    linked_list::{DoublyLinkedList, SinglyLinkedList},
+    priority_queue::PriorityQueue,
+    queue::Queue,
+    trie::Trie,
+};
+
+#[test]
+fn test_core_types() {
+    let list: SinglyLinkedList<_> = (1..=3).collect();
+    assert_eq!(Vec::from(list), [1, 2, 3]);
+
+    let mut doubly: DoublyLinkedList<_> = (1..=3).collect();
+    assert_eq!(doubly.pop_back(), Some(3));
+
+    let mut queue = Queue::new();
+    queue.enqueue(2);
+    queue.enqueue(1);
+    let priority_queue = PriorityQueue::from_queue(queue, |a: &i32, b: &i32| b.cmp(a));
+    assert_eq!(priority_queue.front(), Some(&1));
+
+    let heap = HeapBuilder::new(|a: &i32, b: &i32| a.cmp(b))
+        .extend(vec![3, 1, 2])
+        .build();
+    assert_eq!(heap.root(), Some(&3));
+
+    let mut tree = Node::new_with_value(2);
+    tree.insert_bst(1);
+    tree.insert_bst(3);
+    assert!(tree.is_valid_bst());
+
+    let mut trie = Trie::new();
+    trie.insert("alloc");
+    assert_eq!(trie.find_all_by_prefix("al").len(), 1);
+}